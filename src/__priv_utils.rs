@@ -2,18 +2,46 @@
 
 use core::mem::ManuallyDrop;
 
-use crate::const_panic::{FmtArg as FA, PanicVal as PV};
+use bytemuck::NoUninit;
 
-#[repr(packed)]
+use crate::{
+    const_panic::{FmtArg as FA, PanicVal as PV},
+    pod_hex::PodHex,
+};
+
+// `A` followed by `B`, without padding between or after them.
+#[repr(C, packed)]
 #[derive(Copy)]
-pub(crate) struct Packed<T>(pub(crate) T);
+pub(crate) struct Joined<A, B>(pub(crate) A, pub(crate) B);
 
-impl<T: Copy> Clone for Packed<T> {
+impl<A: Copy, B: Copy> Clone for Joined<A, B> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
+// `T` followed by zeroes, overlayed with the array that its bytes are copied into.
+#[repr(C)]
+union ValueBytes<T: Copy, const N: usize> {
+    value: Joined<T, [u8; N]>,
+    bytes: [u8; N],
+}
+
+// Copies the bytes of `value` into the start of a `[u8; N]`,
+// without borrowing `value`, since that requires Rust 1.83.0 in const fns.
+//
+// If `T` is smaller than `N` bytes, the rest of the array is zeroes,
+// if it's larger, only the first `N` bytes of `value` are copied.
+pub(crate) const fn bytes_into_array<T: NoUninit, const N: usize>(value: T) -> [u8; N] {
+    let overlay = ValueBytes::<T, N> {
+        value: Joined(value, [0; N]),
+    };
+
+    // SAFETY: `Joined<T, [u8; N]>` is at least `N` bytes large,
+    //         and `T: NoUninit` guarantees that its bytes are initialized.
+    unsafe { overlay.bytes }
+}
+
 // allows transmuting between arbitrary Sized types.
 #[repr(C)]
 pub(crate) union Transmuter<F, T> {
@@ -33,6 +61,32 @@ pub union ManuallyDropAsInner<'a, T> {
     pub(crate) inner: &'a T,
 }
 
+// Gets the `start..end` range of `slice`,
+// panicking if the range is out of bounds.
+#[track_caller]
+pub(crate) const fn subslice<T>(slice: &[T], start: usize, end: usize) -> &[T] {
+    if start > end || end > slice.len() {
+        out_of_bounds_range_panic(start, end, slice.len())
+    }
+
+    // SAFETY: `start..end` is within the bounds of `slice`
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().add(start), end - start) }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn out_of_bounds_range_panic(start: usize, end: usize, len: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nrange out of bounds: "),
+        PV::from_usize(start, FA::DEBUG),
+        PV::write_str(".."),
+        PV::from_usize(end, FA::DEBUG),
+        PV::write_str("\nslice length: "),
+        PV::from_usize(len, FA::DEBUG),
+    ]])
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
@@ -74,6 +128,19 @@ pub(crate) const fn unequal_size_panic(size_of_t: usize, size_of_u: usize) -> !
     ]])
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn size_not_multiple_panic(size_of_t: usize, size_of_u: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe size of `T` is not a multiple of the size of `U`"),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+        PV::write_str("\nsize_of::<U>(): "),
+        PV::from_usize(size_of_u, FA::DEBUG),
+    ]])
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
@@ -100,6 +167,17 @@ pub(crate) const fn incompatible_alignment_panic(align_of_t: usize, align_of_u:
     ]])
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn bytes_incompatible_alignment_panic(align_of_t: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe alignment of `T` is larger than the alignment of `u8`"),
+        PV::write_str("\nalign_of::<T>(): "),
+        PV::from_usize(align_of_t, FA::DEBUG),
+    ]])
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
@@ -136,3 +214,195 @@ pub(crate) const fn slice_cast_zst_panic(size_of_t: usize, size_of_u: usize) ->
         PV::from_usize(size_of_u, FA::DEBUG),
     ]])
 }
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn split_unequal_size_panic(
+    size_of_t: usize,
+    size_of_a: usize,
+    size_of_b: usize,
+) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe size of `T` is not the sum of the sizes of `A` and `B`"),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+        PV::write_str("\nsize_of::<A>(): "),
+        PV::from_usize(size_of_a, FA::DEBUG),
+        PV::write_str("\nsize_of::<B>(): "),
+        PV::from_usize(size_of_b, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn split_out_of_bounds_panic(
+    offset: usize,
+    size_of_a: usize,
+    size_of_t: usize,
+) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nattempted to read `A` past the end of `T`"),
+        PV::write_str("\noffset: "),
+        PV::from_usize(offset, FA::DEBUG),
+        PV::write_str("\nsize_of::<A>(): "),
+        PV::from_usize(size_of_a, FA::DEBUG),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn extend_smaller_size_panic(size_of_t: usize, size_of_u: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe size of `U` is smaller than `T`"),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+        PV::write_str("\nsize_of::<U>(): "),
+        PV::from_usize(size_of_u, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn truncate_larger_size_panic(size_of_t: usize, size_of_u: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe size of `U` is larger than `T`"),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+        PV::write_str("\nsize_of::<U>(): "),
+        PV::from_usize(size_of_u, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn reshape_unequal_len_panic(from_len: usize, to_len: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe arrays don't have the same amount of elements"),
+        PV::write_str("\nelements in the input array: "),
+        PV::from_usize(from_len, FA::DEBUG),
+        PV::write_str("\nelements in the output array: "),
+        PV::from_usize(to_len, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn unflatten_slop_panic(slice_len: usize, n: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe slice length is not a multiple of `N`"),
+        PV::write_str("\nslice length: "),
+        PV::from_usize(slice_len, FA::DEBUG),
+        PV::write_str("\nN: "),
+        PV::from_usize(n, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn aligned_incompatible_alignment_panic(
+    align_of_a: usize,
+    align_of_u: usize,
+) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe alignment of the `Aligned` is lower than the target type"),
+        PV::write_str("\nalignment of the `Aligned`: "),
+        PV::from_usize(align_of_a, FA::DEBUG),
+        PV::write_str("\nalignment of the target type: "),
+        PV::from_usize(align_of_u, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn write_overflow_panic(offset: usize, len: usize, remaining: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nattempted to write past the end of the array"),
+        PV::write_str("\nbytes to write: "),
+        PV::from_usize(len, FA::DEBUG),
+        PV::write_str("\noffset: "),
+        PV::from_usize(offset, FA::DEBUG),
+        PV::write_str("\nremaining capacity: "),
+        PV::from_usize(remaining, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn hex_array_len_panic(size_of_t: usize, len: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe length of the array is not twice the size of `T`"),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+        PV::write_str("\narray length: "),
+        PV::from_usize(len, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn hex_str_len_panic(len: usize, size_of_t: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe length of the hex string is not twice the size of the type"),
+        PV::write_str("\nstring length: "),
+        PV::from_usize(len, FA::DEBUG),
+        PV::write_str("\nsize of the type: "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn invalid_hex_digit_panic(hex: &[u8], at: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nfound a byte that isn't a hexadecimal digit"),
+        PV::write_str("\nbyte: "),
+        PV::from_u8(hex[at], FA::DEBUG),
+        PV::write_str("\noffset: "),
+        PV::from_usize(at, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn view_too_short_panic(len: usize, size_of_t: usize) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nthe slice is smaller than `T`"),
+        PV::write_str("\nslice length: "),
+        PV::from_usize(len, FA::DEBUG),
+        PV::write_str("\nsize_of::<T>(): "),
+        PV::from_usize(size_of_t, FA::DEBUG),
+    ]])
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) const fn pod_ne_panic<T: NoUninit>(
+    offset: usize,
+    left: PodHex<'_, T>,
+    right: PodHex<'_, T>,
+) -> ! {
+    crate::const_panic::concat_panic(&[&[
+        PV::write_str("\nassertion failed: the bytes of `left` and `right` are not equal"),
+        PV::write_str("\nfirst differing byte offset: "),
+        PV::from_usize(offset, FA::DEBUG),
+        PV::write_str("\n left: "),
+        left.to_panicval(FA::DEBUG),
+        PV::write_str("\nright: "),
+        right.to_panicval(FA::DEBUG),
+    ]])
+}
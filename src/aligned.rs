@@ -0,0 +1,353 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::{align_of, size_of},
+};
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError, Zeroable};
+
+use crate::{
+    slice_fns::{cast_slice_len, cast_slice_panic},
+    unaligned::Unaligned,
+};
+
+/// A `T` that's aligned to at least the alignment of `A`.
+///
+/// `A` is an alignment marker type, like [`Align8`] or [`Align16`].
+///
+/// References to an `Aligned<A, [T; N]>` coerce to `&Aligned<A, [T]>`,
+/// which can be cast with [`cast_slice_from_aligned`] and [`from_bytes_aligned`]
+/// into types with an alignment of up to `align_of::<A>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_slice_from_aligned, Align8, Aligned};
+///
+/// const BYTES: &Aligned<Align8, [u8]> = &Aligned::new([
+///     1, 0, 0, 0, 0, 0, 0, 0,
+///     0, 2, 0, 0, 0, 0, 0, 0,
+/// ]);
+///
+/// const WORDS: &[u64] = cast_slice_from_aligned(BYTES);
+///
+/// assert_eq!(WORDS, [1u64.to_le(), 0x200u64.to_le()]);
+///
+/// ```
+#[repr(C)]
+pub struct Aligned<A, T: ?Sized> {
+    _align: [A; 0],
+    /// The wrapped value.
+    pub value: T,
+}
+
+impl<A, T> Aligned<A, T> {
+    /// Constructs an `Aligned` from the value it wraps.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self { _align: [], value }
+    }
+}
+
+impl<A, T: ?Sized + Debug> Debug for Aligned<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Aligned").field(&&self.value).finish()
+    }
+}
+
+impl<A: Alignment, T: Copy> Copy for Aligned<A, T> {}
+
+impl<A, T: Clone> Clone for Aligned<A, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<A, T: ?Sized + PartialEq> PartialEq for Aligned<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<A, T: ?Sized + Eq> Eq for Aligned<A, T> {}
+
+impl<A, T: Default> Default for Aligned<A, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+// SAFETY: `Aligned` is a `T` with a zero-length array of `A`
+unsafe impl<A: Alignment, T: Zeroable> Zeroable for Aligned<A, T> {}
+
+/// Trait for the alignment markers used as the `A` type argument of [`Aligned`].
+///
+/// This trait can only be implemented in `constmuck`.
+pub trait Alignment: Copy + sealed::Sealed {
+    /// The alignment of `Self`, in bytes.
+    const ALIGN: usize;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! declare_alignments {
+    ($(($name:ident, $align:literal))*) => {$(
+        #[doc = concat!("Alignment marker for an alignment of ", stringify!($align), " bytes.")]
+        #[repr(align($align))]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+
+        impl Alignment for $name {
+            const ALIGN: usize = $align;
+        }
+    )*};
+}
+
+declare_alignments! {
+    (Align1, 1)
+    (Align2, 2)
+    (Align4, 4)
+    (Align8, 8)
+    (Align16, 16)
+    (Align32, 32)
+    (Align64, 64)
+}
+
+// Associated constant that causes a compile-time error
+// when `Aligned<A, [T]>` isn't aligned enough for `U`.
+struct AlignedCast<A, T, U>(PhantomData<(A, T, U)>);
+
+impl<A: Alignment, T, U> AlignedCast<A, T, U> {
+    const ASSERT_ALIGNED: () = {
+        let align = if A::ALIGN < align_of::<T>() {
+            align_of::<T>()
+        } else {
+            A::ALIGN
+        };
+
+        if align < align_of::<U>() {
+            crate::__priv_utils::aligned_incompatible_alignment_panic(align, align_of::<U>())
+        }
+    };
+}
+
+/// Casts `&Aligned<A, [T]>` to `&[U]`,
+/// erroring at compile-time if `U` is more aligned than `A` and `T`.
+///
+/// If this function does not panic,
+/// the length of the returned slice is `from.value.len() * size_of::<T>() / size_of::<U>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the alignment of `U` is larger than
+/// both the alignments of `A` and `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_slice_from_aligned`]
+/// returns [an error](crate::try_cast_slice_from_aligned#errors).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_slice_from_aligned, Align4, Aligned};
+///
+/// const BYTES: &Aligned<Align4, [u8]> = &Aligned::new(*b"\x03\0\0\0\x05\0\0\0\x08\0\0\0");
+///
+/// const NUMS: &[u32] = cast_slice_from_aligned(BYTES);
+///
+/// assert_eq!(NUMS, [3u32.to_le(), 5u32.to_le(), 8u32.to_le()]);
+///
+/// ```
+///
+/// Casting to types that are more aligned than `A` is an error:
+///
+/// ```compile_fail
+/// use constmuck::{cast_slice_from_aligned, Align4, Aligned};
+///
+/// const NUMS: &[u64] = cast_slice_from_aligned(&Aligned::<Align4, _>::new([0u8; 16]));
+/// ```
+#[track_caller]
+pub const fn cast_slice_from_aligned<A, T, U>(from: &Aligned<A, [T]>) -> &[U]
+where
+    A: Alignment,
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_slice_from_aligned(from) {
+        Ok(x) => x,
+        Err(e) => cast_slice_panic::<T, U>(from.value.len(), e),
+    }
+}
+
+/// Tries to cast `&Aligned<A, [T]>` to `&[U]`,
+/// erroring at compile-time if `U` is more aligned than `A` and `T`.
+///
+/// If this function returns `Ok`,
+/// the length of the returned slice is `from.value.len() * size_of::<T>() / size_of::<U>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the alignment of `U` is larger than
+/// both the alignments of `A` and `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The size of `T` is not equal to `U`, and either type is zero-sized, returning a
+///   `Err(PodCastError::SizeMismatch)`.
+///
+/// - `from.value.len() * size_of::<T>()` does not divide evenly into `size_of::<U>()`,
+///   returning a `Err(PodCastError::OutputSliceWouldHaveSlop)`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_slice_from_aligned, Align16, Aligned, PodCastError};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const BYTES: &Aligned<Align16, [u8]> = &Aligned::new([0xFF; 24]);
+///
+/// const OK: Res<&[u64]> = try_cast_slice_from_aligned(BYTES);
+/// const ERR: Res<&[u128]> = try_cast_slice_from_aligned(BYTES);
+///
+/// assert_eq!(OK, Ok(&[u64::MAX; 3][..]));
+/// assert_eq!(ERR, Err(PodCastError::OutputSliceWouldHaveSlop));
+///
+/// ```
+pub const fn try_cast_slice_from_aligned<A, T, U>(
+    from: &Aligned<A, [T]>,
+) -> Result<&[U], PodCastError>
+where
+    A: Alignment,
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    let _: () = AlignedCast::<A, T, U>::ASSERT_ALIGNED;
+
+    let slice = &from.value;
+
+    // using `Unaligned<U>` to skip the alignment check,
+    // since the assertion above already checked it.
+    match cast_slice_len::<T, Unaligned<U>>(slice.len()) {
+        // SAFETY:
+        // `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        // `U: AnyBitPattern` guarantees that U is valid for any bit pattern.
+        // The slice is aligned to `U` because of the `ASSERT_ALIGNED` assertion,
+        // and `cast_slice_len` checks that the sizes are compatible.
+        Ok(new_len) => unsafe {
+            Ok(core::slice::from_raw_parts(
+                slice.as_ptr().cast::<U>(),
+                new_len,
+            ))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Reinterprets an aligned byte slice as a `&T`,
+/// erroring at compile-time if `T` is more aligned than `A`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the alignment of `T` is larger than `A`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Panics
+///
+/// This function panics if `bytes.value.len() != size_of::<T>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{from_bytes_aligned, Align8, Aligned, AnyBitPattern};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Header {
+///     len: u64,
+///     flags: u32,
+///     kind: u32,
+/// }
+///
+/// const BYTES: &Aligned<Align8, [u8]> = &Aligned::new([0; 16]);
+///
+/// const HEADER: &Header = from_bytes_aligned(BYTES);
+///
+/// assert_eq!(HEADER, &Header{len: 0, flags: 0, kind: 0});
+///
+/// ```
+#[track_caller]
+pub const fn from_bytes_aligned<A, T>(bytes: &Aligned<A, [u8]>) -> &T
+where
+    A: Alignment,
+    T: AnyBitPattern,
+{
+    match try_from_bytes_aligned(bytes) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_bytes_size_panic(bytes.value.len(), size_of::<T>())
+        }
+    }
+}
+
+/// Tries to reinterpret an aligned byte slice as a `&T`,
+/// erroring at compile-time if `T` is more aligned than `A`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the alignment of `T` is larger than `A`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Errors
+///
+/// This returns an `Err(PodCastError::SizeMismatch)`
+/// if `bytes.value.len() != size_of::<T>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_from_bytes_aligned, Align4, Aligned, PodCastError};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const BYTES: &Aligned<Align4, [u8]> = &Aligned::new([0xFF; 4]);
+///
+/// const OK: Res<&i32> = try_from_bytes_aligned(BYTES);
+/// const ERR: Res<&[u16; 3]> = try_from_bytes_aligned(BYTES);
+///
+/// assert_eq!(OK, Ok(&-1));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_from_bytes_aligned<A, T>(bytes: &Aligned<A, [u8]>) -> Result<&T, PodCastError>
+where
+    A: Alignment,
+    T: AnyBitPattern,
+{
+    let _: () = AlignedCast::<A, u8, T>::ASSERT_ALIGNED;
+
+    if bytes.value.len() == size_of::<T>() {
+        // SAFETY: `T: AnyBitPattern` guarantees that `T` is valid for any bit pattern,
+        //         the slice is as large as `T`,
+        //         and it's aligned to `T` because of the `ASSERT_ALIGNED` assertion.
+        unsafe { Ok(&*bytes.value.as_ptr().cast::<T>()) }
+    } else {
+        Err(PodCastError::SizeMismatch)
+    }
+}
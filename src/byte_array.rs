@@ -0,0 +1,122 @@
+use bytemuck::{AnyBitPattern, NoUninit};
+
+use crate::must_cast::{must_cast, must_cast_ref};
+
+/// Converts `T` into a `[u8; N]`, erroring at compile-time if `N != size_of::<T>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `N` is not the size of `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::to_byte_array;
+///
+/// const BYTES: [u8; 6] = to_byte_array([0x0102u16.to_be(), 3, 5]);
+///
+/// assert_eq!(BYTES[..2], [1, 2]);
+/// assert_eq!(BYTES[2..], [3u16.to_ne_bytes(), 5u16.to_ne_bytes()].concat());
+///
+/// ```
+#[inline(always)]
+pub const fn to_byte_array<T, const N: usize>(from: T) -> [u8; N]
+where
+    T: NoUninit,
+{
+    must_cast(from)
+}
+
+/// Converts a `[u8; N]` into a `T`, erroring at compile-time if `N != size_of::<T>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `N` is not the size of `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::from_byte_array;
+///
+/// const NUM: u32 = from_byte_array(*b"ABCD");
+///
+/// assert_eq!(NUM, u32::from_ne_bytes(*b"ABCD"));
+///
+/// ```
+#[inline(always)]
+pub const fn from_byte_array<T, const N: usize>(from: [u8; N]) -> T
+where
+    T: AnyBitPattern,
+{
+    must_cast(from)
+}
+
+/// Casts `&T` to `&[u8; N]`, erroring at compile-time if `N != size_of::<T>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `N` is not the size of `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::to_byte_array_ref;
+///
+/// const BYTES: &[u8; 4] = to_byte_array_ref(&0xDEAD_BEEF_u32.to_be());
+///
+/// assert_eq!(BYTES, &[0xDE, 0xAD, 0xBE, 0xEF]);
+///
+/// ```
+#[inline(always)]
+pub const fn to_byte_array_ref<T, const N: usize>(from: &T) -> &[u8; N]
+where
+    T: NoUninit,
+{
+    must_cast_ref(from)
+}
+
+/// Casts `&[u8; N]` to `&T`, erroring at compile-time if `N != size_of::<T>()`,
+/// or if `T` has an alignment larger than `1`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error in these cases:
+/// - `N` is not the size of `T`
+/// - The alignment of `T` is larger than `1`
+///
+/// Because the checks happen after monomorphization,
+/// `cargo check` might not report the errors, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{from_byte_array_ref, AnyBitPattern};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Header {
+///     magic: [u8; 2],
+///     version: u8,
+/// }
+///
+/// const HEADER: &Header = from_byte_array_ref(b"MZ\x03");
+///
+/// assert_eq!(HEADER, &Header{magic: *b"MZ", version: 3});
+///
+/// ```
+#[inline(always)]
+pub const fn from_byte_array_ref<T, const N: usize>(from: &[u8; N]) -> &T
+where
+    T: AnyBitPattern,
+{
+    must_cast_ref(from)
+}
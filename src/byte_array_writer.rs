@@ -0,0 +1,254 @@
+use core::mem::size_of;
+
+use bytemuck::NoUninit;
+
+use crate::__priv_utils::{bytes_into_array, subslice, write_overflow_panic};
+
+/// Builds a `[u8; N]` by writing values into it, in const contexts.
+///
+/// Every writing method takes the writer by value,
+/// returning the writer with the written bytes,
+/// so that it can be used in const contexts.
+///
+/// The bytes that aren't written to are zeroes.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{ByteArrayWriter, NoUninit};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, NoUninit)]
+/// struct Header {
+///     magic: [u8; 3],
+///     count: u8,
+/// }
+///
+/// const IMAGE: [u8; 12] = ByteArrayWriter::new()
+///     .write(Header{magic: *b"IMG", count: 2})
+///     .write_bytes(b"ab")
+///     .pad_to(4)
+///     .write([3u8, 5])
+///     .write_bytes(b"cd")
+///     .finish();
+///
+/// assert_eq!(IMAGE, *b"IMG\x02ab\0\0\x03\x05cd");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteArrayWriter<const N: usize> {
+    buffer: [u8; N],
+    offset: usize,
+}
+
+impl<const N: usize> ByteArrayWriter<N> {
+    /// Constructs a `ByteArrayWriter` that writes to the start of a zeroed array.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            offset: 0,
+        }
+    }
+
+    /// The amount of bytes that have been written or padded so far.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteArrayWriter;
+    ///
+    /// const OFFSET: usize = ByteArrayWriter::<8>::new().write(3u16).offset();
+    ///
+    /// assert_eq!(OFFSET, 2);
+    ///
+    /// ```
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The amount of bytes that can still be written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteArrayWriter;
+    ///
+    /// const REM: usize = ByteArrayWriter::<8>::new().write(3u16).remaining();
+    ///
+    /// assert_eq!(REM, 6);
+    ///
+    /// ```
+    pub const fn remaining(&self) -> usize {
+        N - self.offset
+    }
+
+    /// Writes the bytes of `value`, advancing the writer past them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `size_of::<T>()` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteArrayWriter;
+    ///
+    /// const ARR: [u8; 6] = ByteArrayWriter::new()
+    ///     .write(0x0102u16.to_be())
+    ///     .write(0x03040506u32.to_be())
+    ///     .finish();
+    ///
+    /// assert_eq!(ARR, [1, 2, 3, 4, 5, 6]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn write<T: NoUninit>(self, value: T) -> Self {
+        if size_of::<T>() > self.remaining() {
+            write_overflow_panic(self.offset, size_of::<T>(), self.remaining())
+        }
+
+        let bytes = bytes_into_array::<T, N>(value);
+
+        self.write_bytes(subslice(&bytes, 0, size_of::<T>()))
+    }
+
+    /// Writes `bytes`, advancing the writer past them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `bytes.len()` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteArrayWriter;
+    ///
+    /// const ARR: [u8; 8] = ByteArrayWriter::new()
+    ///     .write_bytes(b"foo")
+    ///     .write_bytes(b"bar")
+    ///     .finish();
+    ///
+    /// assert_eq!(ARR, *b"foobar\0\0");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn write_bytes(mut self, bytes: &[u8]) -> Self {
+        if bytes.len() > self.remaining() {
+            write_overflow_panic(self.offset, bytes.len(), self.remaining())
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buffer[self.offset + i] = bytes[i];
+            i += 1;
+        }
+        self.offset += bytes.len();
+
+        self
+    }
+
+    /// Advances the writer until its offset is a multiple of `align`,
+    /// leaving the skipped bytes as zeroes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is `0`,
+    /// or if the padding would go past the end of the array.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteArrayWriter;
+    ///
+    /// const ARR: [u8; 8] = ByteArrayWriter::new()
+    ///     .write_bytes(&[3, 5, 8])
+    ///     .pad_to(4)
+    ///     .write_bytes(&[13])
+    ///     .pad_to(2)
+    ///     .write_bytes(&[21])
+    ///     .finish();
+    ///
+    /// assert_eq!(ARR, [3, 5, 8, 0, 13, 0, 21, 0]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn pad_to(mut self, align: usize) -> Self {
+        if align == 0 {
+            panic!("`align` must not be zero")
+        }
+
+        let padding = match self.offset % align {
+            0 => 0,
+            rem => align - rem,
+        };
+
+        if padding > self.remaining() {
+            write_overflow_panic(self.offset, padding, self.remaining())
+        }
+
+        self.offset += padding;
+
+        self
+    }
+
+    /// Returns the written array.
+    ///
+    /// The bytes that weren't written to are zeroes.
+    pub const fn finish(self) -> [u8; N] {
+        self.buffer
+    }
+}
+
+impl<const N: usize> Default for ByteArrayWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Concatenates the bytes of [`NoUninit`] constants into a `[u8; N]`,
+/// where `N` is the sum of their sizes.
+///
+/// The arguments must be usable in a `const` item,
+/// because they're used to compute `N`.
+///
+/// This is equivalent to writing each argument in order with
+/// [`ByteArrayWriter::write`], into a `ByteArrayWriter` that's exactly large enough.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{concat_bytes_of, NoUninit};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, NoUninit)]
+/// struct Header {
+///     magic: [u8; 4],
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// const HEADER: Header = Header { magic: *b"ROM!", len: 3u16.to_be(), flags: 0 };
+/// const TABLE: [u16; 3] = [3u16.to_be(), 5u16.to_be(), 8u16.to_be()];
+/// const TRAILER: u8 = 0xFF;
+///
+/// const IMAGE: [u8; 15] = concat_bytes_of!(HEADER, TABLE, TRAILER);
+///
+/// assert_eq!(IMAGE, *b"ROM!\0\x03\0\0\0\x03\0\x05\0\x08\xFF");
+///
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_of {
+    ($($value:expr),* $(,)?) => {{
+        const __LEN: usize = 0 $( + $crate::__::size_of_val_pod(&$value) )*;
+
+        $crate::ByteArrayWriter::<__LEN>::new()
+            $( .write($value) )*
+            .finish()
+    }};
+}
+
+// Used by `concat_bytes_of` to get the size of each argument
+#[doc(hidden)]
+pub const fn size_of_val_pod<T: NoUninit>(_: &T) -> usize {
+    core::mem::size_of::<T>()
+}
@@ -0,0 +1,344 @@
+use core::{fmt, mem::size_of};
+
+use bytemuck::AnyBitPattern;
+
+use crate::{__priv_utils::subslice, pod::read_unaligned_unchecked};
+
+/// A cursor for reading [`AnyBitPattern`] values out of a byte slice.
+///
+/// Every reading method takes the reader by value,
+/// returning the read value along with the advanced reader,
+/// so that it can be used in const contexts.
+///
+/// The values are read with the same layout as [`pod_read_unaligned`],
+/// which means that the bytes don't need to be aligned for the read type,
+/// and integers are read in native endianness.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{AnyBitPattern, ByteReader, ByteReaderError};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Entry {
+///     id: [u8; 2],
+///     flags: u8,
+/// }
+///
+/// // the format is:
+/// // - a 1 byte entry count
+/// // - a 1 byte padding that is skipped
+/// // - the entries
+/// const BLOB: &[u8] = &[2, 0, 10, 11, 1, 20, 21, 0];
+///
+/// const ENTRIES: Result<[Entry; 2], ByteReaderError> = read_entries(ByteReader::new(BLOB));
+///
+/// assert_eq!(
+///     ENTRIES,
+///     Ok([
+///         Entry{id: [10, 11], flags: 1},
+///         Entry{id: [20, 21], flags: 0},
+///     ]),
+/// );
+///
+/// const fn read_entries(reader: ByteReader<'_>) -> Result<[Entry; 2], ByteReaderError> {
+///     let (count, reader) = match reader.read::<u8>() {
+///         Ok(x) => x,
+///         Err(e) => return Err(e),
+///     };
+///     assert!(count == 2);
+///
+///     let reader = match reader.skip(1) {
+///         Ok(x) => x,
+///         Err(e) => return Err(e),
+///     };
+///
+///     match reader.read_array::<Entry, 2>() {
+///         Ok((entries, reader)) => {
+///             assert!(reader.remaining().is_empty());
+///             Ok(entries)
+///         }
+///         Err(e) => Err(e),
+///     }
+/// }
+///
+/// ```
+///
+/// [`pod_read_unaligned`]: crate::pod_read_unaligned
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    /// Constructs a `ByteReader` that reads from the start of `bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteReader;
+    ///
+    /// const READER: ByteReader<'_> = ByteReader::new(&[3, 5, 8]);
+    ///
+    /// assert_eq!(READER.offset(), 0);
+    /// assert_eq!(READER.remaining(), [3, 5, 8]);
+    ///
+    /// ```
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// The amount of bytes that have been read or skipped so far.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteReader;
+    ///
+    /// const OFFSET: usize = {
+    ///     match ByteReader::new(&[3, 5, 8, 13]).read::<[u8; 3]>() {
+    ///         Ok((_, reader)) => reader.offset(),
+    ///         Err(_) => panic!(),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(OFFSET, 3);
+    ///
+    /// ```
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The bytes that haven't been read yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::ByteReader;
+    ///
+    /// const REM: &[u8] = {
+    ///     match ByteReader::new(&[3, 5, 8, 13]).skip(1) {
+    ///         Ok(reader) => reader.remaining(),
+    ///         Err(_) => panic!(),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(REM, [5, 8, 13]);
+    ///
+    /// ```
+    pub const fn remaining(&self) -> &'a [u8] {
+        subslice(self.bytes, self.offset, self.bytes.len())
+    }
+
+    /// Reads a `T` without advancing the reader.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there are fewer than `size_of::<T>()` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{ByteReader, ByteReaderError};
+    ///
+    /// const READER: ByteReader<'_> = ByteReader::new(&[3, 5, 8]);
+    ///
+    /// const OK: Result<[u8; 2], ByteReaderError> = READER.peek();
+    /// const ERR: Result<u32, ByteReaderError> = READER.peek();
+    ///
+    /// assert_eq!(OK, Ok([3, 5]));
+    ///
+    /// let err = ERR.unwrap_err();
+    /// assert_eq!(err.offset(), 0);
+    /// assert_eq!(err.requested(), 4);
+    /// assert_eq!(err.available(), 3);
+    ///
+    /// ```
+    pub const fn peek<T: AnyBitPattern>(&self) -> Result<T, ByteReaderError> {
+        match self.check_available(size_of::<T>()) {
+            // SAFETY: `T: AnyBitPattern` is valid for any bit pattern,
+            //         and `check_available` ensures that there are
+            //         at least `size_of::<T>()` bytes remaining
+            Ok(()) => unsafe { Ok(read_unaligned_unchecked(self.remaining())) },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads a `T`, returning it along with the reader advanced past it.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there are fewer than `size_of::<T>()` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{ByteReader, ByteReaderError};
+    ///
+    /// const OK: Result<(u8, ByteReader<'_>), ByteReaderError> =
+    ///     ByteReader::new(&[3, 5, 8]).read();
+    ///
+    /// let (byte, reader) = OK.unwrap();
+    /// assert_eq!(byte, 3);
+    /// assert_eq!(reader.remaining(), [5, 8]);
+    ///
+    /// ```
+    pub const fn read<T: AnyBitPattern>(self) -> Result<(T, Self), ByteReaderError> {
+        match self.peek::<T>() {
+            Ok(x) => Ok((x, self.advance(size_of::<T>()))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads a `[T; N]`, returning it along with the reader advanced past it.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there are fewer than `size_of::<[T; N]>()` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{ByteReader, ByteReaderError};
+    ///
+    /// const READER: ByteReader<'_> = ByteReader::new(&[3, 0, 5, 0, 8, 0]);
+    ///
+    /// const OK: Result<([u16; 3], ByteReader<'_>), ByteReaderError> = READER.read_array();
+    /// const ERR: Result<([u16; 4], ByteReader<'_>), ByteReaderError> = READER.read_array();
+    ///
+    /// let (arr, _) = OK.unwrap();
+    /// assert_eq!(arr, [3, 5, 8].map(|x: u16| x.to_le()));
+    ///
+    /// assert_eq!(ERR.unwrap_err().requested(), 8);
+    ///
+    /// ```
+    pub const fn read_array<T: AnyBitPattern, const N: usize>(
+        self,
+    ) -> Result<([T; N], Self), ByteReaderError> {
+        let len = size_of::<[T; N]>();
+        match self.check_available(len) {
+            // SAFETY: `[T; N]` is valid for any bit pattern because `T` is,
+            //         and `check_available` ensures that there are
+            //         at least `size_of::<[T; N]>()` bytes remaining
+            Ok(()) => unsafe {
+                let array = read_unaligned_unchecked::<[T; N]>(self.remaining());
+                Ok((array, self.advance(len)))
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads `len` bytes, returning them along with the reader advanced past them.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there are fewer than `len` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{ByteReader, ByteReaderError};
+    ///
+    /// const OK: Result<(&[u8], ByteReader<'_>), ByteReaderError> =
+    ///     ByteReader::new(b"hello world").read_bytes(5);
+    ///
+    /// let (hello, reader) = OK.unwrap();
+    /// assert_eq!(hello, b"hello");
+    /// assert_eq!(reader.remaining(), b" world");
+    ///
+    /// ```
+    pub const fn read_bytes(self, len: usize) -> Result<(&'a [u8], Self), ByteReaderError> {
+        match self.check_available(len) {
+            Ok(()) => Ok((
+                subslice(self.bytes, self.offset, self.offset + len),
+                self.advance(len),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Advances the reader past `len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there are fewer than `len` bytes remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{ByteReader, ByteReaderError};
+    ///
+    /// const READER: ByteReader<'_> = ByteReader::new(&[3, 5, 8]);
+    ///
+    /// const OK: Result<ByteReader<'_>, ByteReaderError> = READER.skip(2);
+    /// const ERR: Result<ByteReader<'_>, ByteReaderError> = READER.skip(4);
+    ///
+    /// assert_eq!(OK.unwrap().remaining(), [8]);
+    /// assert_eq!(ERR.unwrap_err().available(), 3);
+    ///
+    /// ```
+    pub const fn skip(self, len: usize) -> Result<Self, ByteReaderError> {
+        match self.check_available(len) {
+            Ok(()) => Ok(self.advance(len)),
+            Err(e) => Err(e),
+        }
+    }
+
+    const fn check_available(&self, requested: usize) -> Result<(), ByteReaderError> {
+        let available = self.bytes.len() - self.offset;
+        if requested <= available {
+            Ok(())
+        } else {
+            Err(ByteReaderError {
+                offset: self.offset,
+                requested,
+                available,
+            })
+        }
+    }
+
+    const fn advance(self, len: usize) -> Self {
+        Self {
+            bytes: self.bytes,
+            offset: self.offset + len,
+        }
+    }
+}
+
+/// Error returned by [`ByteReader`] methods when
+/// there aren't enough bytes remaining.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteReaderError {
+    offset: usize,
+    requested: usize,
+    available: usize,
+}
+
+impl ByteReaderError {
+    /// The offset (relative to the start of the read bytes) at which reading failed.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The amount of bytes that were requested.
+    pub const fn requested(&self) -> usize {
+        self.requested
+    }
+
+    /// The amount of bytes that were remaining.
+    pub const fn available(&self) -> usize {
+        self.available
+    }
+}
+
+impl fmt::Display for ByteReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "attempted to read {} bytes at offset {}, but only {} bytes are remaining",
+            self.requested, self.offset, self.available,
+        )
+    }
+}
@@ -0,0 +1,571 @@
+//! Const equivalents of [`bytemuck::checked`] functions,
+//! for casting into types that have invalid bit patterns.
+//!
+//! The functions in this module cast into [`CheckedBitPattern`] types,
+//! checking that the bit patterns are valid for the target type,
+//! and returning `Err(CheckedCastError::InvalidBitPattern)` if they aren't.
+//!
+//! [`CheckedBitPattern`] is implemented for `bool`, `char`, the `NonZero*` integers,
+//! and can be implemented for any [`Contiguous`] type with [`Validator::contiguous`].
+//!
+//! # Example
+//!
+//! Decoding an enum from bytes.
+//!
+//! ```rust
+//! use constmuck::checked::{self, CheckedBitPattern, CheckedCastError, Validator};
+//! use constmuck::Contiguous;
+//!
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq, Contiguous, Copy, Clone)]
+//! enum Shape {
+//!     Circle = 1,
+//!     Square = 2,
+//!     Triangle = 3,
+//! }
+//!
+//! impl CheckedBitPattern for Shape {
+//!     const VALIDATOR: Validator<Self> = Validator::contiguous();
+//! }
+//!
+//! type Res<T> = Result<T, CheckedCastError>;
+//!
+//! const SHAPES: Res<&[Shape]> = checked::try_cast_slice(&[3u8, 1, 2]);
+//! assert_eq!(SHAPES, Ok(&[Shape::Triangle, Shape::Circle, Shape::Square][..]));
+//!
+//! const ERR: Res<&[Shape]> = checked::try_cast_slice(&[3u8, 4]);
+//! assert_eq!(ERR, Err(CheckedCastError::InvalidBitPattern));
+//!
+//! ```
+//!
+//! [`Contiguous`]: bytemuck::Contiguous
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+
+use bytemuck::{AnyBitPattern, Contiguous, NoUninit, PodCastError};
+
+#[doc(no_inline)]
+pub use bytemuck::checked::CheckedCastError;
+
+use crate::{
+    __priv_utils::{bytes_into_array, subslice},
+    contiguous::{__IntegerWit, Integer},
+    pod::read_unaligned_unchecked,
+    slice_fns::{bytes_of, cast_slice_len},
+};
+
+/// Trait for types that can be checked for valid bit patterns in const contexts.
+///
+/// This is the const equivalent of [`bytemuck::checked::CheckedBitPattern`].
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::checked::{self, CheckedBitPattern, CheckedCastError, Validator};
+/// use constmuck::Contiguous;
+///
+/// #[repr(i16)]
+/// #[derive(Debug, PartialEq, Contiguous, Copy, Clone)]
+/// enum Temperature {
+///     Cold = -1,
+///     Mild = 0,
+///     Hot = 1,
+/// }
+///
+/// impl CheckedBitPattern for Temperature {
+///     const VALIDATOR: Validator<Self> = Validator::contiguous();
+/// }
+///
+/// const HOT: Result<Temperature, CheckedCastError> = checked::try_cast(1i16);
+/// const COLD: Result<Temperature, CheckedCastError> = checked::try_cast(u16::MAX);
+/// const ERR: Result<Temperature, CheckedCastError> = checked::try_cast(2u16);
+///
+/// assert_eq!(HOT, Ok(Temperature::Hot));
+/// assert_eq!(COLD, Ok(Temperature::Cold));
+/// assert_eq!(ERR, Err(CheckedCastError::InvalidBitPattern));
+///
+/// ```
+pub trait CheckedBitPattern: Copy {
+    /// Checks whether bytes are a valid bit pattern for `Self`.
+    const VALIDATOR: Validator<Self>;
+}
+
+/// Checks whether bytes are a valid bit pattern for `T`.
+///
+/// This is used as the value of [`CheckedBitPattern::VALIDATOR`].
+pub struct Validator<T> {
+    kind: ValidatorKind,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ValidatorKind {
+    AnyBitPattern,
+    Char,
+    NonZero,
+    Signed { min: i128, max: i128 },
+    Unsigned { min: u128, max: u128 },
+}
+
+impl<T> Copy for Validator<T> {}
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Debug for Validator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator")
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<T: AnyBitPattern> Validator<T> {
+    /// Constructs a `Validator` that accepts any bit pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::checked::{self, CheckedBitPattern, CheckedCastError, Validator};
+    /// use constmuck::{AnyBitPattern, NoUninit};
+    ///
+    /// #[repr(transparent)]
+    /// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern, NoUninit)]
+    /// struct Meters(u32);
+    ///
+    /// impl CheckedBitPattern for Meters {
+    ///     const VALIDATOR: Validator<Self> = Validator::any_bit_pattern();
+    /// }
+    ///
+    /// const METERS: Result<Meters, CheckedCastError> = checked::try_cast(u32::MAX);
+    ///
+    /// assert_eq!(METERS, Ok(Meters(u32::MAX)));
+    ///
+    /// ```
+    pub const fn any_bit_pattern() -> Self {
+        Self::from_kind(ValidatorKind::AnyBitPattern)
+    }
+}
+
+impl<T> Validator<T> {
+    const fn from_kind(kind: ValidatorKind) -> Self {
+        Self {
+            kind,
+            _marker: PhantomData,
+        }
+    }
+
+    // Checks that the bytes of `value` are a valid bit pattern for `T`,
+    // without borrowing `value`, since that requires Rust 1.83.0.
+    //
+    // `size_of::<V>()` must equal `size_of::<T>()`.
+    const fn is_valid_value<V: NoUninit>(self, value: V) -> bool {
+        match self.kind {
+            ValidatorKind::AnyBitPattern => true,
+            // the other validators read integers, which are at most 16 bytes large
+            _ => self.is_valid(subslice(
+                &bytes_into_array::<V, 16>(value),
+                0,
+                size_of::<V>(),
+            )),
+        }
+    }
+
+    // Checks that `bytes` is a valid bit pattern for `T`.
+    //
+    // `bytes.len()` must equal `size_of::<T>()`.
+    const fn is_valid(self, bytes: &[u8]) -> bool {
+        match self.kind {
+            ValidatorKind::AnyBitPattern => true,
+            ValidatorKind::Char => {
+                let c = read_unsigned(bytes);
+                c < 0xD800 || 0xE000 <= c && c <= 0x10FFFF
+            }
+            ValidatorKind::NonZero => read_unsigned(bytes) != 0,
+            ValidatorKind::Signed { min, max } => {
+                let n = read_signed(bytes);
+                min <= n && n <= max
+            }
+            ValidatorKind::Unsigned { min, max } => {
+                let n = read_unsigned(bytes);
+                min <= n && n <= max
+            }
+        }
+    }
+}
+
+macro_rules! declare_contiguous_ctor {
+    (
+        signed[$($s_variant:ident)*]
+        unsigned[$($u_variant:ident)*]
+    ) => {
+        impl<T> Validator<T>
+        where
+            T: Contiguous,
+            T::Int: Integer,
+        {
+            /// Constructs a `Validator` that accepts the integers between
+            /// [`T::MIN_VALUE`] and [`T::MAX_VALUE`] inclusive,
+            /// the same integers that [`contiguous::from_integer`] accepts.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use constmuck::checked::{self, CheckedBitPattern, CheckedCastError, Validator};
+            /// use constmuck::Contiguous;
+            ///
+            /// #[repr(u32)]
+            /// #[derive(Debug, PartialEq, Contiguous, Copy, Clone)]
+            /// enum Level {
+            ///     Low = 10,
+            ///     High = 11,
+            /// }
+            ///
+            /// impl CheckedBitPattern for Level {
+            ///     const VALIDATOR: Validator<Self> = Validator::contiguous();
+            /// }
+            ///
+            /// type Res<T> = Result<T, CheckedCastError>;
+            ///
+            /// const HIGH: Res<Level> = checked::try_pod_read_unaligned(&11u32.to_ne_bytes());
+            /// const ERR: Res<Level> = checked::try_pod_read_unaligned(&12u32.to_ne_bytes());
+            ///
+            /// assert_eq!(HIGH, Ok(Level::High));
+            /// assert_eq!(ERR, Err(CheckedCastError::InvalidBitPattern));
+            ///
+            /// ```
+            ///
+            /// [`T::MIN_VALUE`]: Contiguous::MIN_VALUE
+            /// [`T::MAX_VALUE`]: Contiguous::MAX_VALUE
+            /// [`contiguous::from_integer`]: crate::contiguous::from_integer
+            pub const fn contiguous() -> Self {
+                let kind = match <T::Int>::__WITNESS {
+                    $(
+                        __IntegerWit::$s_variant(te) => ValidatorKind::Signed {
+                            min: te.to_right(T::MIN_VALUE) as i128,
+                            max: te.to_right(T::MAX_VALUE) as i128,
+                        },
+                    )*
+                    $(
+                        __IntegerWit::$u_variant(te) => ValidatorKind::Unsigned {
+                            min: te.to_right(T::MIN_VALUE) as u128,
+                            max: te.to_right(T::MAX_VALUE) as u128,
+                        },
+                    )*
+                };
+
+                Self::from_kind(kind)
+            }
+        }
+    };
+}
+
+declare_contiguous_ctor! {
+    signed[I8 I16 I32 I64 I128 Isize]
+    unsigned[U8 U16 U32 U64 U128 Usize]
+}
+
+// Reads a native-endian unsigned integer with the same size as `bytes`.
+const fn read_unsigned(bytes: &[u8]) -> u128 {
+    // SAFETY: each branch reads an integer with the same size as `bytes`
+    unsafe {
+        match bytes.len() {
+            1 => read_unaligned_unchecked::<u8>(bytes) as u128,
+            2 => read_unaligned_unchecked::<u16>(bytes) as u128,
+            4 => read_unaligned_unchecked::<u32>(bytes) as u128,
+            8 => read_unaligned_unchecked::<u64>(bytes) as u128,
+            16 => read_unaligned_unchecked::<u128>(bytes),
+            _ => panic!("unsupported integer size"),
+        }
+    }
+}
+
+// Reads a native-endian signed integer with the same size as `bytes`.
+const fn read_signed(bytes: &[u8]) -> i128 {
+    // SAFETY: each branch reads an integer with the same size as `bytes`
+    unsafe {
+        match bytes.len() {
+            1 => read_unaligned_unchecked::<i8>(bytes) as i128,
+            2 => read_unaligned_unchecked::<i16>(bytes) as i128,
+            4 => read_unaligned_unchecked::<i32>(bytes) as i128,
+            8 => read_unaligned_unchecked::<i64>(bytes) as i128,
+            16 => read_unaligned_unchecked::<i128>(bytes),
+            _ => panic!("unsupported integer size"),
+        }
+    }
+}
+
+macro_rules! impl_checked_bit_pattern {
+    ($validator:expr => $($type:ty),* $(,)?) => {$(
+        impl CheckedBitPattern for $type {
+            const VALIDATOR: Validator<Self> = $validator;
+        }
+    )*};
+}
+
+impl_checked_bit_pattern! {
+    Validator::any_bit_pattern() =>
+        u8, u16, u32, u64, u128, usize,
+        i8, i16, i32, i64, i128, isize,
+        f32, f64,
+}
+
+impl_checked_bit_pattern! {
+    Validator::contiguous() =>
+        bool,
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+}
+
+impl_checked_bit_pattern! {
+    Validator::from_kind(ValidatorKind::NonZero) =>
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+}
+
+impl_checked_bit_pattern! {
+    Validator::from_kind(ValidatorKind::Char) => char
+}
+
+/// Tries to cast `T` into `U`, checking that the bits are valid for `U`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The size of `T` is not equal to `U`, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))`.
+///
+/// - The bits of `from` are not a valid bit pattern for `U`, returning a
+///   `Err(CheckedCastError::InvalidBitPattern)`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::checked::{self, CheckedCastError};
+/// use constmuck::PodCastError;
+///
+/// type Res<T> = Result<T, CheckedCastError>;
+///
+/// const TRUE: Res<bool> = checked::try_cast(1u8);
+/// const CHAR: Res<char> = checked::try_cast(0x61u32);
+///
+/// const ERR_BOOL: Res<bool> = checked::try_cast(2u8);
+/// const ERR_CHAR: Res<char> = checked::try_cast(0xD800u32);
+/// const ERR_SIZE: Res<char> = checked::try_cast(0x61u8);
+///
+/// assert_eq!(TRUE, Ok(true));
+/// assert_eq!(CHAR, Ok('a'));
+///
+/// assert_eq!(ERR_BOOL, Err(CheckedCastError::InvalidBitPattern));
+/// assert_eq!(ERR_CHAR, Err(CheckedCastError::InvalidBitPattern));
+/// assert_eq!(
+///     ERR_SIZE,
+///     Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch)),
+/// );
+///
+/// ```
+pub const fn try_cast<T, U>(from: T) -> Result<U, CheckedCastError>
+where
+    T: NoUninit,
+    U: CheckedBitPattern,
+{
+    if size_of::<T>() != size_of::<U>() {
+        Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+    } else if !U::VALIDATOR.is_valid_value(from) {
+        Err(CheckedCastError::InvalidBitPattern)
+    } else {
+        // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        //         the above checks ensure that they're the same size,
+        //         and that `from` is a valid `U`.
+        unsafe { Ok(__priv_transmute!(T, U, from)) }
+    }
+}
+
+/// Tries to cast `&T` to `&U`, checking that the bits are valid for `U`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The alignment of `T` is larger than `U`, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::TargetAlignmentGreaterAndInputNotAligned))`.
+///
+/// - The size of `T` is not equal to `U`, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))`.
+///
+/// - The bits of `*from` are not a valid bit pattern for `U`, returning a
+///   `Err(CheckedCastError::InvalidBitPattern)`.
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::checked::try_cast_ref`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::checked::{self, CheckedCastError};
+///
+/// use std::num::NonZeroU16;
+///
+/// type Res<T> = Result<T, CheckedCastError>;
+///
+/// const OK: Res<&NonZeroU16> = checked::try_cast_ref(&1000u16);
+/// const ERR: Res<&NonZeroU16> = checked::try_cast_ref(&0i16);
+///
+/// assert_eq!(OK.unwrap().get(), 1000);
+/// assert_eq!(ERR, Err(CheckedCastError::InvalidBitPattern));
+///
+/// ```
+pub const fn try_cast_ref<T, U>(from: &T) -> Result<&U, CheckedCastError>
+where
+    T: NoUninit,
+    U: CheckedBitPattern,
+{
+    if align_of::<T>() < align_of::<U>() {
+        Err(CheckedCastError::PodCastError(
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned,
+        ))
+    } else if size_of::<T>() != size_of::<U>() {
+        Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+    } else if !U::VALIDATOR.is_valid(bytes_of(from)) {
+        Err(CheckedCastError::InvalidBitPattern)
+    } else {
+        // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        //         the above checks ensure that `T` is at least as aligned as `U`,
+        //         that they're the same size, and that `*from` is a valid `U`.
+        unsafe { Ok(__priv_transmute_ref!(T, U, from)) }
+    }
+}
+
+/// Tries to cast `&[T]` to `&[U]`, checking that the bits of every element are valid for `U`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The alignment of `T` is larger than `U`, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::TargetAlignmentGreaterAndInputNotAligned))`.
+///
+/// - The size of `T` is not equal to `U`, and either type is zero-sized, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))`.
+///
+/// - `from.len() * size_of::<T>()` does not divide evenly into `size_of::<U>()`,
+///   returning a `Err(CheckedCastError::PodCastError(PodCastError::OutputSliceWouldHaveSlop))`.
+///
+/// - Any of the `U`s would have an invalid bit pattern, returning a
+///   `Err(CheckedCastError::InvalidBitPattern)`.
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::checked::try_cast_slice`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::checked::{self, CheckedCastError};
+/// use constmuck::PodCastError;
+///
+/// type Res<T> = Result<T, CheckedCastError>;
+///
+/// const BOOLS: Res<&[bool]> = checked::try_cast_slice(&[0u8, 1, 1, 0]);
+/// assert_eq!(BOOLS, Ok(&[false, true, true, false][..]));
+///
+/// const ERR_BITS: Res<&[bool]> = checked::try_cast_slice(&[0u8, 1, 2]);
+/// assert_eq!(ERR_BITS, Err(CheckedCastError::InvalidBitPattern));
+///
+/// const CHARS: Res<&[char]> = checked::try_cast_slice(&[0x61u32, 0x62]);
+/// assert_eq!(CHARS, Ok(&['a', 'b'][..]));
+///
+/// const ERR_ALIGN: Res<&[char]> = checked::try_cast_slice(&[0u8; 4]);
+/// assert_eq!(
+///     ERR_ALIGN,
+///     Err(CheckedCastError::PodCastError(
+///         PodCastError::TargetAlignmentGreaterAndInputNotAligned
+///     )),
+/// );
+///
+/// ```
+pub const fn try_cast_slice<T, U>(from: &[T]) -> Result<&[U], CheckedCastError>
+where
+    T: NoUninit,
+    U: CheckedBitPattern,
+{
+    let new_len = match cast_slice_len::<T, U>(from.len()) {
+        Ok(x) => x,
+        Err(e) => return Err(CheckedCastError::PodCastError(e)),
+    };
+
+    // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes
+    let bytes = unsafe {
+        core::slice::from_raw_parts(from.as_ptr().cast::<u8>(), new_len * size_of::<U>())
+    };
+
+    let mut i = 0;
+    while i < new_len {
+        let elem_bytes = subslice(bytes, i * size_of::<U>(), (i + 1) * size_of::<U>());
+        if !U::VALIDATOR.is_valid(elem_bytes) {
+            return Err(CheckedCastError::InvalidBitPattern);
+        }
+        i += 1;
+    }
+
+    // SAFETY: `cast_slice_len` checks that the size and alignment of `T` and `U`
+    //         are compatible, and the loop above checked that every `U` is valid.
+    unsafe {
+        Ok(core::slice::from_raw_parts(
+            from.as_ptr().cast::<U>(),
+            new_len,
+        ))
+    }
+}
+
+/// Tries to read a `T` out of a byte slice, checking that the bits are valid for `T`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - `bytes.len()` is not equal to `size_of::<T>()`, returning a
+///   `Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))`.
+///
+/// - `bytes` is not a valid bit pattern for `T`, returning a
+///   `Err(CheckedCastError::InvalidBitPattern)`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::checked::{self, CheckedCastError};
+///
+/// type Res<T> = Result<T, CheckedCastError>;
+///
+/// const CHAR: Res<char> = checked::try_pod_read_unaligned(&0x2764u32.to_ne_bytes());
+/// const ERR: Res<char> = checked::try_pod_read_unaligned(&0x110000u32.to_ne_bytes());
+///
+/// assert_eq!(CHAR, Ok('❤'));
+/// assert_eq!(ERR, Err(CheckedCastError::InvalidBitPattern));
+///
+/// ```
+pub const fn try_pod_read_unaligned<T>(bytes: &[u8]) -> Result<T, CheckedCastError>
+where
+    T: CheckedBitPattern,
+{
+    if bytes.len() != size_of::<T>() {
+        Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+    } else if !T::VALIDATOR.is_valid(bytes) {
+        Err(CheckedCastError::InvalidBitPattern)
+    } else {
+        // SAFETY: the slice is as large as `T`,
+        //         and the above check ensures that it's a valid `T`.
+        unsafe { Ok(read_unaligned_unchecked(bytes)) }
+    }
+}
@@ -0,0 +1,140 @@
+use core::cmp::Ordering;
+
+use bytemuck::NoUninit;
+
+use crate::slice_fns::{bytes_of, slice_bytes};
+
+/// Compares the bytes of `left` and `right` for equality.
+///
+/// This is equivalent to `bytes_of(left) == bytes_of(right)`,
+/// which can differ from the `PartialEq` impl of `T`,
+/// e.g.: floats compare `0.0` and `-0.0` as equal, and NaNs as not equal to themselves.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::pod_eq;
+///
+/// const PAIRS: [[u16; 2]; 3] = [[3, 5], [8, 13], [3, 5]];
+///
+/// const _: () = assert!(pod_eq(&PAIRS[0], &PAIRS[2]));
+/// const _: () = assert!(!pod_eq(&PAIRS[0], &PAIRS[1]));
+///
+/// assert!(!pod_eq(&0.0f32, &-0.0f32));
+/// assert!(pod_eq(&f64::NAN, &f64::NAN));
+///
+/// ```
+pub const fn pod_eq<T: NoUninit>(left: &T, right: &T) -> bool {
+    bytes_eq(bytes_of(left), bytes_of(right))
+}
+
+/// Compares the bytes of `left` and `right` lexicographically.
+///
+/// This is equivalent to `bytes_of(left).cmp(bytes_of(right))`,
+/// which can differ from the `Ord` impl of `T`,
+/// e.g.: on little-endian targets, integers are compared from their least significant byte.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::pod_cmp;
+///
+/// use std::cmp::Ordering;
+///
+/// const CMPS: [Ordering; 3] = [
+///     pod_cmp(b"bar", b"baz"),
+///     pod_cmp(b"foo", b"foo"),
+///     pod_cmp(&[5u8, 3], &[3, 5]),
+/// ];
+///
+/// assert_eq!(CMPS, [Ordering::Less, Ordering::Equal, Ordering::Greater]);
+///
+/// // big-endian integers compare like the integers themselves
+/// assert_eq!(pod_cmp(&0x0100u16.to_be(), &0x00FFu16.to_be()), Ordering::Greater);
+///
+/// ```
+pub const fn pod_cmp<T: NoUninit>(left: &T, right: &T) -> Ordering {
+    let left = bytes_of(left);
+    let right = bytes_of(right);
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return if left[i] < right[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+
+    Ordering::Equal
+}
+
+/// Compares the bytes of `left` and `right` for equality,
+/// returning `false` if they have different lengths.
+///
+/// This is equivalent to [`pod_eq`] on each pair of elements.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::slice_pod_eq;
+///
+/// const TABLE: &[u32] = &[3, 5, 8, 13];
+/// const DEDUPED: &[u32] = &[3, 5, 8, 13];
+///
+/// const _: () = assert!(slice_pod_eq(TABLE, DEDUPED));
+/// const _: () = assert!(!slice_pod_eq(TABLE, &[3, 5, 8]));
+/// const _: () = assert!(!slice_pod_eq(TABLE, &[3, 5, 8, 21]));
+///
+/// ```
+pub const fn slice_pod_eq<T: NoUninit>(left: &[T], right: &[T]) -> bool {
+    left.len() == right.len() && bytes_eq(slice_bytes(left), slice_bytes(right))
+}
+
+/// Whether all the bytes of `value` are zero.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::is_zeroed;
+///
+/// const _: () = assert!(is_zeroed(&[0u64; 4]));
+/// const _: () = assert!(!is_zeroed(&[0u64, 0, 1, 0]));
+///
+/// // negative zero has its sign bit set
+/// assert!(is_zeroed(&0.0f32));
+/// assert!(!is_zeroed(&-0.0f32));
+///
+/// ```
+pub const fn is_zeroed<T: NoUninit>(value: &T) -> bool {
+    let bytes = bytes_of(value);
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn bytes_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
@@ -0,0 +1,230 @@
+//! Integer types with an explicit byte order,
+//! and functions for reading integers in a specific byte order.
+//!
+//! The casting functions in this crate use native endianness,
+//! the types in this module allow declaring [`Pod`] types whose integer fields
+//! have the same byte order on every target.
+//!
+//! # Example
+//!
+//! Declaring the header of a file format,
+//! which is read the same on little-endian and big-endian targets.
+//!
+//! ```rust
+//! use constmuck::endian::{U16Be, U32Le};
+//! use constmuck::{Pod, Zeroable};
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Copy, Clone, PartialEq, Pod, Zeroable)]
+//! struct Header {
+//!     magic: [u8; 2],
+//!     version: U16Be,
+//!     len: U32Le,
+//! }
+//!
+//! const HEADER_BYTES: &[u8] = b"PK\x00\x03\x10\x00\x00\x00";
+//!
+//! const HEADER: Header = constmuck::pod_read_unaligned(HEADER_BYTES);
+//!
+//! assert_eq!(HEADER.magic, *b"PK");
+//! assert_eq!(HEADER.version.get(), 3);
+//! assert_eq!(HEADER.len.get(), 16);
+//!
+//! ```
+//!
+
+use core::fmt::{self, Debug};
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::contiguous::{__IntegerWit, Integer};
+
+macro_rules! declare_endian_types {
+    ($(
+        ($Int:ident, $size:literal, $Le:ident, $Be:ident)
+    )*) => {$(
+        declare_endian_types! {
+            @single $Int, $size, $Le, "little", from_le_bytes, to_le_bytes
+        }
+        declare_endian_types! {
+            @single $Int, $size, $Be, "big", from_be_bytes, to_be_bytes
+        }
+    )*};
+    (@single
+        $Int:ident, $size:literal, $Type:ident, $endian:literal, $from_bytes:ident, $to_bytes:ident
+    ) => {
+        #[doc = concat!("A `", stringify!($Int), "` stored in ", $endian, "-endian byte order.")]
+        ///
+        /// This type has an alignment of `1`,
+        /// and has the same size as the integer it stores.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use constmuck::endian::", stringify!($Type), ";")]
+        ///
+        #[doc = concat!("const VALUE: ", stringify!($Type), " = ", stringify!($Type), "::new(100);")]
+        ///
+        /// assert_eq!(VALUE.get(), 100);
+        #[doc = concat!("assert_eq!(VALUE.to_bytes(), 100", stringify!($Int), ".", stringify!($to_bytes), "());")]
+        ///
+        /// ```
+        #[repr(transparent)]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $Type([u8; $size]);
+
+        impl $Type {
+            #[doc = concat!("Constructs a `", stringify!($Type), "` from the integer it stores.")]
+            #[inline(always)]
+            pub const fn new(value: $Int) -> Self {
+                Self(value.$to_bytes())
+            }
+
+            #[doc = concat!("Gets the `", stringify!($Int), "` that this stores.")]
+            #[inline(always)]
+            pub const fn get(self) -> $Int {
+                $Int::$from_bytes(self.0)
+            }
+
+            #[doc = concat!("Constructs a `", stringify!($Type), "` from its ", $endian, "-endian bytes.")]
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            #[doc = concat!("Gets the ", $endian, "-endian bytes of this `", stringify!($Type), "`.")]
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; $size] {
+                self.0
+            }
+        }
+
+        impl Debug for $Type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl Default for $Type {
+            fn default() -> Self {
+                Self([0; $size])
+            }
+        }
+
+        impl From<$Int> for $Type {
+            fn from(value: $Int) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$Type> for $Int {
+            fn from(value: $Type) -> Self {
+                value.get()
+            }
+        }
+
+        // SAFETY: `$Type` is a `#[repr(transparent)]` wrapper around a byte array
+        unsafe impl Zeroable for $Type {}
+
+        // SAFETY: `$Type` is a `#[repr(transparent)]` wrapper around a byte array
+        unsafe impl Pod for $Type {}
+    };
+}
+
+declare_endian_types! {
+    (u16,  2,  U16Le,  U16Be)
+    (u32,  4,  U32Le,  U32Be)
+    (u64,  8,  U64Le,  U64Be)
+    (u128, 16, U128Le, U128Be)
+    (i16,  2,  I16Le,  I16Be)
+    (i32,  4,  I32Le,  I32Be)
+    (i64,  8,  I64Le,  I64Be)
+    (i128, 16, I128Le, I128Be)
+}
+
+macro_rules! declare_read_fns {
+    ($(($variant:ident, $Int:ident))*) => {
+        /// Reads a little-endian `T` out of a byte slice.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bytes.len() != size_of::<T>()`,
+        /// like [`pod_read_unaligned`] does.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use constmuck::endian::read_le;
+        ///
+        /// const BYTES: &[u8] = &[0x78, 0x56, 0x34, 0x12];
+        ///
+        /// const NUM: u32 = read_le(BYTES);
+        /// const HALVES: [u16; 2] = [read_le(&[0x78, 0x56]), read_le(&[0x34, 0x12])];
+        ///
+        /// assert_eq!(NUM, 0x12345678);
+        /// assert_eq!(HALVES, [0x5678, 0x1234]);
+        ///
+        /// ```
+        ///
+        /// [`pod_read_unaligned`]: crate::pod_read_unaligned
+        #[track_caller]
+        pub const fn read_le<T: Integer>(bytes: &[u8]) -> T {
+            match T::__WITNESS {
+                $(
+                    __IntegerWit::$variant(te) => {
+                        te.to_left($Int::from_le(crate::pod_read_unaligned(bytes)))
+                    }
+                )*
+            }
+        }
+
+        /// Reads a big-endian `T` out of a byte slice.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bytes.len() != size_of::<T>()`,
+        /// like [`pod_read_unaligned`] does.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use constmuck::endian::read_be;
+        ///
+        /// const BYTES: &[u8] = &[0x12, 0x34, 0x56, 0x78];
+        ///
+        /// const NUM: u32 = read_be(BYTES);
+        /// const SIGNED: i16 = read_be(&[0xFF, 0xFE]);
+        ///
+        /// assert_eq!(NUM, 0x12345678);
+        /// assert_eq!(SIGNED, -2);
+        ///
+        /// ```
+        ///
+        /// [`pod_read_unaligned`]: crate::pod_read_unaligned
+        #[track_caller]
+        pub const fn read_be<T: Integer>(bytes: &[u8]) -> T {
+            match T::__WITNESS {
+                $(
+                    __IntegerWit::$variant(te) => {
+                        te.to_left($Int::from_be(crate::pod_read_unaligned(bytes)))
+                    }
+                )*
+            }
+        }
+    };
+}
+
+declare_read_fns! {
+    (I8,    i8)
+    (I16,   i16)
+    (I32,   i32)
+    (I64,   i64)
+    (I128,  i128)
+    (Isize, isize)
+    (U8,    u8)
+    (U16,   u16)
+    (U32,   u32)
+    (U64,   u64)
+    (U128,  u128)
+    (Usize, usize)
+}
@@ -0,0 +1,266 @@
+//! Const checksums and hashes of the bytes of [`NoUninit`] values.
+//!
+//! Every function in this module hashes the bytes returned by
+//! [`bytes_of`] (or its equivalent for slices),
+//! producing the same results as the standard runtime implementations
+//! of each algorithm.
+//!
+//! Because the bytes of integers depend on the endianness of the target,
+//! so do the hashes of values that contain multi-byte integers.
+//! The types in the [`endian`](crate::endian) module can be used to get
+//! the same hash on every target.
+//!
+//! # Example
+//!
+//! Computing the checksum of a table at compile time,
+//! to check its integrity at runtime.
+//!
+//! ```rust
+//! use constmuck::hash;
+//!
+//! const TABLE: [u8; 9] = *b"123456789";
+//!
+//! const CHECKSUM: u32 = hash::crc32(&TABLE);
+//!
+//! assert_eq!(CHECKSUM, 0xCBF4_3926);
+//!
+//! // At boot, the table can be re-hashed and compared against the checksum
+//! assert_eq!(hash::crc32_slice(&TABLE[..]), CHECKSUM);
+//!
+//! ```
+//!
+
+use bytemuck::NoUninit;
+
+use crate::slice_fns::{bytes_of, slice_bytes};
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of the bytes of `value`.
+///
+/// This is the same checksum that zlib, gzip, and PNG use.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::crc32;
+///
+/// const CHECKSUM: u32 = crc32(b"The quick brown fox jumps over the lazy dog");
+///
+/// assert_eq!(CHECKSUM, 0x414F_A339);
+///
+/// ```
+pub const fn crc32<T: NoUninit>(value: &T) -> u32 {
+    crc32_bytes(bytes_of(value))
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of the bytes of `slice`.
+///
+/// This is equivalent to [`crc32`] over the bytes of every element.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::crc32_slice;
+///
+/// const CHECKSUM: u32 = crc32_slice(&[0x3231u16.to_le(), 0x3433u16.to_le()]);
+///
+/// assert_eq!(CHECKSUM, crc32_slice(b"1234"));
+///
+/// ```
+pub const fn crc32_slice<T: NoUninit>(slice: &[T]) -> u32 {
+    crc32_bytes(slice_bytes(slice))
+}
+
+/// Computes the 64-bit FNV-1a hash of the bytes of `value`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::fnv1a_64;
+///
+/// const HASH: u64 = fnv1a_64(b"foobar");
+///
+/// assert_eq!(HASH, 0x8594_4171_F739_67E8);
+///
+/// ```
+pub const fn fnv1a_64<T: NoUninit>(value: &T) -> u64 {
+    fnv1a_64_bytes(bytes_of(value))
+}
+
+/// Computes the 64-bit FNV-1a hash of the bytes of `slice`.
+///
+/// This is equivalent to [`fnv1a_64`] over the bytes of every element.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::fnv1a_64_slice;
+///
+/// const NAMES: [&[u8]; 2] = [b"foo", b"bar"];
+///
+/// const HASHES: [u64; 2] = [fnv1a_64_slice(NAMES[0]), fnv1a_64_slice(NAMES[1])];
+///
+/// assert_eq!(HASHES, [0xDCB2_7518_FED9_D577, 0x0039_3419_1339_461A]);
+///
+/// ```
+pub const fn fnv1a_64_slice<T: NoUninit>(slice: &[T]) -> u64 {
+    fnv1a_64_bytes(slice_bytes(slice))
+}
+
+/// Computes the 32-bit xxHash (XXH32) of the bytes of `value`, starting from `seed`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::xxh32;
+///
+/// const HASHES: [u32; 2] = [xxh32(b"abc", 0), xxh32(b"abc", 1)];
+///
+/// assert_eq!(HASHES, [0x32D1_53FF, 0xAA3D_A8FF]);
+///
+/// ```
+pub const fn xxh32<T: NoUninit>(value: &T, seed: u32) -> u32 {
+    xxh32_bytes(bytes_of(value), seed)
+}
+
+/// Computes the 32-bit xxHash (XXH32) of the bytes of `slice`, starting from `seed`.
+///
+/// This is equivalent to [`xxh32`] over the bytes of every element.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::hash::xxh32_slice;
+///
+/// const HASH: u32 = xxh32_slice(b"Nobody inspects the spammish repetition", 0);
+///
+/// assert_eq!(HASH, 0xE229_3B2F);
+///
+/// ```
+pub const fn xxh32_slice<T: NoUninit>(slice: &[T], seed: u32) -> u32 {
+    xxh32_bytes(slice_bytes(slice), seed)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+const fn crc32_bytes(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = (crc >> 8) ^ CRC32_TABLE[((crc as u8) ^ bytes[i]) as usize];
+        i += 1;
+    }
+
+    !crc
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+const fn fnv1a_64_bytes(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+const XXH_PRIME32_1: u32 = 0x9E37_79B1;
+const XXH_PRIME32_2: u32 = 0x85EB_CA77;
+const XXH_PRIME32_3: u32 = 0xC2B2_AE3D;
+const XXH_PRIME32_4: u32 = 0x27D4_EB2F;
+const XXH_PRIME32_5: u32 = 0x1656_67B1;
+
+const fn xxh32_bytes(bytes: &[u8], seed: u32) -> u32 {
+    let len = bytes.len();
+    let mut i = 0;
+
+    let mut hash = if len >= 16 {
+        let mut acc = [
+            seed.wrapping_add(XXH_PRIME32_1).wrapping_add(XXH_PRIME32_2),
+            seed.wrapping_add(XXH_PRIME32_2),
+            seed,
+            seed.wrapping_sub(XXH_PRIME32_1),
+        ];
+
+        while len - i >= 16 {
+            let mut lane = 0;
+            while lane < 4 {
+                acc[lane] = xxh32_round(acc[lane], read_u32_le(bytes, i));
+                i += 4;
+                lane += 1;
+            }
+        }
+
+        acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18))
+    } else {
+        seed.wrapping_add(XXH_PRIME32_5)
+    };
+
+    // XXH32 only mixes in the low 32 bits of the length
+    hash = hash.wrapping_add(len as u32);
+
+    while len - i >= 4 {
+        hash = hash.wrapping_add(read_u32_le(bytes, i).wrapping_mul(XXH_PRIME32_3));
+        hash = hash.rotate_left(17).wrapping_mul(XXH_PRIME32_4);
+        i += 4;
+    }
+
+    while i < len {
+        hash = hash.wrapping_add((bytes[i] as u32).wrapping_mul(XXH_PRIME32_5));
+        hash = hash.rotate_left(11).wrapping_mul(XXH_PRIME32_1);
+        i += 1;
+    }
+
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(XXH_PRIME32_2);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(XXH_PRIME32_3);
+    hash ^= hash >> 16;
+
+    hash
+}
+
+const fn xxh32_round(acc: u32, input: u32) -> u32 {
+    acc.wrapping_add(input.wrapping_mul(XXH_PRIME32_2))
+        .rotate_left(13)
+        .wrapping_mul(XXH_PRIME32_1)
+}
+
+const fn read_u32_le(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
@@ -0,0 +1,213 @@
+use core::{marker::PhantomData, mem::size_of};
+
+use bytemuck::NoUninit;
+
+use crate::{
+    __priv_utils::{hex_array_len_panic, hex_str_len_panic, invalid_hex_digit_panic},
+    slice_fns::bytes_of,
+};
+
+// Associated constant that causes a compile-time error
+// when `[u8; N]` can't hold the hex digits of `T`.
+struct HexArray<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> HexArray<T, N> {
+    const ASSERT_LEN: () = if N != 2 * size_of::<T>() {
+        hex_array_len_panic(size_of::<T>(), N)
+    };
+}
+
+/// Converts the bytes of `value` into lowercase hexadecimal digits,
+/// erroring at compile-time if `N != 2 * size_of::<T>()`.
+///
+/// The digits are written in the order of the bytes returned by
+/// [`bytes_of`](crate::bytes_of),
+/// two digits per byte, most significant digit first.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `N` is not twice the size of `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::to_hex_array;
+///
+/// const BUILD_ID: [u8; 4] = [0xDE, 0xAD, 0x0B, 0x07];
+///
+/// const HEX: [u8; 8] = to_hex_array(&BUILD_ID);
+///
+/// assert_eq!(&HEX, b"dead0b07");
+///
+/// // integers are written in the order that they're stored in
+/// assert_eq!(&to_hex_array::<_, 4>(&0xABCDu16.to_be()), b"abcd");
+/// assert_eq!(&to_hex_array::<_, 4>(&0xABCDu16.to_le()), b"cdab");
+///
+/// ```
+///
+/// Using an array of the wrong length is an error:
+///
+/// ```compile_fail
+/// const HEX: [u8; 4] = constmuck::to_hex_array(&0u32);
+/// ```
+pub const fn to_hex_array<T, const N: usize>(value: &T) -> [u8; N]
+where
+    T: NoUninit,
+{
+    let _: () = HexArray::<T, N>::ASSERT_LEN;
+
+    write_hex(bytes_of(value), LOWER_DIGITS)
+}
+
+/// Converts the bytes of `value` into uppercase hexadecimal digits,
+/// erroring at compile-time if `N != 2 * size_of::<T>()`.
+///
+/// This is the uppercase equivalent of [`to_hex_array`].
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `N` is not twice the size of `T`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::to_hex_array_upper;
+///
+/// const HEX: [u8; 8] = to_hex_array_upper(&[0xDEu8, 0xAD, 0x0B, 0x07]);
+///
+/// assert_eq!(&HEX, b"DEAD0B07");
+///
+/// ```
+pub const fn to_hex_array_upper<T, const N: usize>(value: &T) -> [u8; N]
+where
+    T: NoUninit,
+{
+    let _: () = HexArray::<T, N>::ASSERT_LEN;
+
+    write_hex(bytes_of(value), UPPER_DIGITS)
+}
+
+/// Parses a string of hexadecimal digits into any [`AnyBitPattern`](crate::AnyBitPattern) type.
+///
+/// The string is parsed into bytes in order, two digits per byte,
+/// then the bytes are read as the type like
+/// [`pod_read_unaligned`](crate::pod_read_unaligned) does.
+/// This is the inverse of [`to_hex_array`].
+///
+/// Both uppercase and lowercase digits are accepted.
+///
+/// The parsed value is usable in const contexts.
+///
+/// # Syntax
+///
+/// This macro takes the string, followed by `as` and the type to parse:
+///
+/// ```text
+/// pod_from_hex!("deadbeef" as Type)
+/// ```
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error in these cases:
+/// - The string contains a non-hexadecimal character.
+/// - The length of the string is not twice the size of `Type`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{pod_from_hex, AnyBitPattern};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Uuid {
+///     time_low: [u8; 4],
+///     time_mid: [u8; 2],
+///     time_hi_and_version: [u8; 2],
+///     rest: [u8; 8],
+/// }
+///
+/// const ID: Uuid = pod_from_hex!("123e4567E89B12d3a456426614174000" as Uuid);
+///
+/// assert_eq!(ID.time_low, [0x12, 0x3E, 0x45, 0x67]);
+/// assert_eq!(ID.rest, [0xA4, 0x56, 0x42, 0x66, 0x14, 0x17, 0x40, 0x00]);
+///
+/// assert_eq!(pod_from_hex!("0102" as u16), u16::from_be(0x0102));
+///
+/// ```
+///
+/// Invalid digits are an error:
+///
+/// ```compile_fail
+/// const N: u16 = constmuck::pod_from_hex!("12g4" as u16);
+/// ```
+///
+/// As is a string with the wrong length:
+///
+/// ```compile_fail
+/// const N: u32 = constmuck::pod_from_hex!("123456" as u32);
+/// ```
+#[macro_export]
+macro_rules! pod_from_hex {
+    ($hex:literal as $ty:ty $(,)?) => {{
+        const __LEN: usize = $crate::__::size_of::<$ty>();
+
+        const __BYTES: [u8; __LEN] = $crate::__::parse_hex::<__LEN>($hex);
+
+        const __OUT: $ty = $crate::pod_read_unaligned::<$ty>(&__BYTES);
+
+        __OUT
+    }};
+}
+
+const LOWER_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+const fn write_hex<const N: usize>(bytes: &[u8], digits: &[u8; 16]) -> [u8; N] {
+    let mut out = [0u8; N];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        out[2 * i] = digits[(bytes[i] >> 4) as usize];
+        out[2 * i + 1] = digits[(bytes[i] & 0xF) as usize];
+        i += 1;
+    }
+
+    out
+}
+
+// Used by `pod_from_hex` to parse the string into bytes
+#[doc(hidden)]
+#[track_caller]
+pub const fn parse_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let hex = hex.as_bytes();
+
+    if hex.len() != 2 * N {
+        hex_str_len_panic(hex.len(), N)
+    }
+
+    let mut out = [0u8; N];
+
+    let mut i = 0;
+    while i < N {
+        out[i] = (hex_digit(hex, 2 * i) << 4) | hex_digit(hex, 2 * i + 1);
+        i += 1;
+    }
+
+    out
+}
+
+#[track_caller]
+const fn hex_digit(hex: &[u8], at: usize) -> u8 {
+    match hex[at] {
+        b @ b'0'..=b'9' => b - b'0',
+        b @ b'a'..=b'f' => b - b'a' + 10,
+        b @ b'A'..=b'F' => b - b'A' + 10,
+        _ => invalid_hex_digit_panic(hex, at),
+    }
+}
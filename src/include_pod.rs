@@ -0,0 +1,306 @@
+use core::mem::size_of;
+
+use bytemuck::{AnyBitPattern, Pod};
+
+use crate::{
+    slice_fns::{cast_slice_len, cast_slice_panic},
+    unaligned::Unaligned,
+};
+
+/// Embeds a file as a `&'static T`, reading the bytes in native endianness by default.
+///
+/// The file path is relative to the file that invokes this macro,
+/// the same as [`include_bytes`].
+///
+/// The returned reference is aligned for `T`,
+/// and is usable in const contexts.
+///
+/// # Constants
+///
+/// The contents of the file are stored in a promoted constant rather than a `static`,
+/// because statics can't be referenced from constants in Rust 1.65.
+/// This means that every expansion of this macro gets its own copy of the file.
+/// To embed the file once, you can store the reference in a `static`:
+///
+/// ```rust
+/// use constmuck::include_pod;
+///
+/// static FIBS: &[u16; 8] = include_pod!("../tests/data/fib_u16be.bin" as [u16; 8]);
+/// #
+/// # assert_eq!(FIBS.len(), 8);
+/// ```
+///
+/// # Syntax
+///
+/// This macro takes the path to the file, followed by `as` and the type to read:
+///
+/// ```text
+/// include_pod!("file.bin" as Type)
+/// ```
+///
+/// Optionally followed by the byte order of the file, which can be
+/// `native` (the default), `little`, or `big`:
+///
+/// ```text
+/// include_pod!("file.bin" as Type, endian = big)
+/// ```
+///
+/// `little` and `big` are only supported when `Type` is an integer, a float,
+/// or (possibly nested) arrays of those.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the size of the file is not
+/// equal to `size_of::<Type>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::include_pod;
+///
+/// // this file contains the first 8 fibonacci numbers, as big-endian `u16`s
+/// const FIBS: &[u16; 8] = include_pod!("../tests/data/fib_u16be.bin" as [u16; 8], endian = big);
+///
+/// assert_eq!(FIBS, &[1, 1, 2, 3, 5, 8, 13, 21]);
+///
+/// // reading the same file as native-endian `u16`s
+/// const NATIVE: &[u16; 8] = include_pod!("../tests/data/fib_u16be.bin" as [u16; 8]);
+///
+/// assert_eq!(NATIVE, &[1u16, 1, 2, 3, 5, 8, 13, 21].map(u16::to_be));
+///
+/// ```
+///
+/// Reading a file into a type of a different size is an error:
+///
+/// ```compile_fail
+/// const FIBS: &[u16; 7] = constmuck::include_pod!("../tests/data/fib_u16be.bin" as [u16; 7]);
+/// ```
+#[macro_export]
+macro_rules! include_pod {
+    ($path:literal as $ty:ty $(,)?) => {
+        $crate::__include_pod_inner!(include_pod_ref, $path, $ty, $ty, native)
+    };
+    ($path:literal as $ty:ty, endian = $endian:ident $(,)?) => {
+        $crate::__include_pod_inner!(include_pod_ref, $path, $ty, $ty, $endian)
+    };
+}
+
+/// Embeds a file as a `&'static [T]`, reading the bytes in native endianness by default.
+///
+/// The file path is relative to the file that invokes this macro,
+/// the same as [`include_bytes`].
+///
+/// The returned slice is aligned for `T`,
+/// and is usable in const contexts.
+///
+/// Like with [`include_pod`](crate::include_pod#constants),
+/// every expansion of this macro gets its own copy of the file.
+///
+/// # Syntax
+///
+/// This macro takes the path to the file, followed by `as` and the element type to read:
+///
+/// ```text
+/// include_pod_slice!("file.bin" as Type)
+/// ```
+///
+/// Optionally followed by the byte order of the file, which can be
+/// `native` (the default), `little`, or `big`:
+///
+/// ```text
+/// include_pod_slice!("file.bin" as Type, endian = little)
+/// ```
+///
+/// `little` and `big` are only supported when `Type` is an integer, a float,
+/// or (possibly nested) arrays of those.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error in the cases where
+/// [`cast_slice_alt`](crate::cast_slice_alt) would panic for
+/// casting the `[u8]` contents of the file into a `[Type]`,
+/// except for alignment errors, since the file is aligned for `Type`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::include_pod_slice;
+///
+/// // this file contains the first 8 fibonacci numbers, as big-endian `u16`s
+/// const FIBS: &[u16] = include_pod_slice!("../tests/data/fib_u16be.bin" as u16, endian = big);
+///
+/// assert_eq!(FIBS, [1, 1, 2, 3, 5, 8, 13, 21]);
+///
+/// // reading the same file as pairs of big-endian `u16`s
+/// const PAIRS: &[[u16; 2]] = include_pod_slice!(
+///     "../tests/data/fib_u16be.bin" as [u16; 2],
+///     endian = big,
+/// );
+///
+/// assert_eq!(PAIRS, [[1, 1], [2, 3], [5, 8], [13, 21]]);
+///
+/// ```
+///
+/// Reading a file whose size isn't a multiple of the size of `Type` is an error:
+///
+/// ```compile_fail
+/// const TRIPLES: &[[u16; 3]] =
+///     constmuck::include_pod_slice!("../tests/data/fib_u16be.bin" as [u16; 3]);
+/// ```
+#[macro_export]
+macro_rules! include_pod_slice {
+    ($path:literal as $ty:ty $(,)?) => {
+        $crate::__include_pod_inner!(include_pod_slice, $path, [$ty], $ty, native)
+    };
+    ($path:literal as $ty:ty, endian = $endian:ident $(,)?) => {
+        $crate::__include_pod_inner!(include_pod_slice, $path, [$ty], $ty, $endian)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __include_pod_inner {
+    ($func:ident, $path:literal, $ret:ty, $ty:ty, $endian:ident) => {{
+        const __LEN: usize = $crate::__::include_bytes!($path).len();
+
+        const __BYTES: &$crate::__::AlignedBytes<$ty, [u8; __LEN]> = &$crate::__::AlignedBytes::new(
+        $crate::__include_pod_inner!(@bytes $path, $ty, __LEN, $endian),
+        );
+
+        const __OUT: &'static $ret = $crate::__::$func::<$ty>(__BYTES);
+
+        __OUT
+    }};
+    (@bytes $path:literal, $ty:ty, $len:ident, native) => {
+        *$crate::__::include_bytes!($path)
+    };
+    (@bytes $path:literal, $ty:ty, $len:ident, little) => {
+        $crate::__::reorder_bytes::<$ty, $len>(
+            *$crate::__::include_bytes!($path),
+            $crate::__::Endian::Little,
+        )
+    };
+    (@bytes $path:literal, $ty:ty, $len:ident, big) => {
+        $crate::__::reorder_bytes::<$ty, $len>(
+            *$crate::__::include_bytes!($path),
+            $crate::__::Endian::Big,
+        )
+    };
+    (@bytes $path:literal, $ty:ty, $len:ident, $endian:ident) => {
+        $crate::__::compile_error!(concat!(
+            "expected the endianness to be `native`, `little`, or `big`, found: `",
+            stringify!($endian),
+            "`",
+        ))
+    };
+}
+
+// Bytes that are aligned to `T`, used to store the contents of the included file.
+#[doc(hidden)]
+#[derive(Debug)]
+#[repr(C)]
+pub struct AlignedBytes<T, B: ?Sized> {
+    _align: [T; 0],
+    bytes: B,
+}
+
+impl<T, const N: usize> AlignedBytes<T, [u8; N]> {
+    #[doc(hidden)]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { _align: [], bytes }
+    }
+}
+
+/// Types that can be byte-swapped as a sequence of words of the same size.
+#[doc(hidden)]
+pub trait ByteOrderWord: Pod {
+    const WORD_SIZE: usize;
+}
+
+macro_rules! impl_byte_order_word {
+    ($($ty:ty)*) => {$(
+        impl ByteOrderWord for $ty {
+            const WORD_SIZE: usize = size_of::<$ty>();
+        }
+    )*};
+}
+
+impl_byte_order_word! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    f32 f64
+}
+
+impl<T: ByteOrderWord, const N: usize> ByteOrderWord for [T; N] {
+    const WORD_SIZE: usize = T::WORD_SIZE;
+}
+
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+// Reverses the bytes of every `T::WORD_SIZE`-sized word in `bytes`,
+// if `endian` isn't the native endianness.
+#[doc(hidden)]
+pub const fn reorder_bytes<T: ByteOrderWord, const N: usize>(
+    mut bytes: [u8; N],
+    endian: Endian,
+) -> [u8; N] {
+    let is_native = match endian {
+        Endian::Little => cfg!(target_endian = "little"),
+        Endian::Big => cfg!(target_endian = "big"),
+    };
+
+    if is_native {
+        return bytes;
+    }
+
+    let word_size = T::WORD_SIZE;
+    let mut start = 0;
+    while start + word_size <= N {
+        let mut i = 0;
+        while i < word_size / 2 {
+            let tmp = bytes[start + i];
+            bytes[start + i] = bytes[start + word_size - 1 - i];
+            bytes[start + word_size - 1 - i] = tmp;
+            i += 1;
+        }
+        start += word_size;
+    }
+
+    bytes
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn include_pod_ref<T: AnyBitPattern>(bytes: &AlignedBytes<T, [u8]>) -> &T {
+    let bytes = &bytes.bytes;
+
+    if bytes.len() != size_of::<T>() {
+        crate::__priv_utils::unequal_bytes_size_panic(bytes.len(), size_of::<T>())
+    }
+
+    // SAFETY: `T: AnyBitPattern` guarantees that `T` is valid for any bit pattern,
+    //         the slice is as large as `T`,
+    //         and `AlignedBytes<T, _>` ensures that the slice is aligned to `T`.
+    unsafe { &*bytes.as_ptr().cast::<T>() }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn include_pod_slice<T: AnyBitPattern>(bytes: &AlignedBytes<T, [u8]>) -> &[T] {
+    let bytes = &bytes.bytes;
+
+    // using `Unaligned<T>` to skip the alignment check,
+    // because `AlignedBytes<T, _>` ensures that the slice is aligned to `T`.
+    match cast_slice_len::<u8, Unaligned<T>>(bytes.len()) {
+        // SAFETY: `T: AnyBitPattern` guarantees that `T` is valid for any bit pattern,
+        //         `cast_slice_len` checks that the sizes are compatible,
+        //         and `AlignedBytes<T, _>` ensures that the slice is aligned to `T`.
+        Ok(len) => unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), len) },
+        Err(e) => cast_slice_panic::<u8, T>(bytes.len(), e),
+    }
+}
@@ -121,9 +121,18 @@
 //! Enables [additional safety checks](#additional-checks) for detecting some
 //! Undefined Behavior.
 //!
+//! - `"konst"`(disabled by default):
+//! Implements [`konst`]'s `ConstIntoIter` trait for [`UnalignedSlice`] and its iterators,
+//! so that they can be used with the `konst::iter` macros.
+//!
 //! - `"rust_1_75"` (disabled by default):
 //! allows [`constmuck::zeroed`] to construct types of any size.
 //!
+//! - `"rust_1_83"` (disabled by default):
+//! enables the `rust_1_75` feature, and the functions that cast mutable references:
+//! `cast_mut`, `try_cast_mut`, `cast_slice_mut_alt`,
+//! `try_cast_slice_mut_alt`, and `bytes_of_mut`.
+//!
 //! - `"rust_latest_stable"` (disabled by default):
 //! enables all `"rust_1_*"` features.
 //!
@@ -151,6 +160,8 @@
 #![deny(missing_debug_implementations)]
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
+// the lists in the docs continue their items in unindented lines
+#![allow(clippy::doc_lazy_continuation)]
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
@@ -159,12 +170,52 @@ pub struct ReadmeTest;
 #[macro_use]
 mod macros;
 
+mod aligned;
+
+mod byte_array;
+
+mod byte_array_writer;
+
+mod byte_reader;
+
+pub mod checked;
+
+mod compare;
+
 pub mod contiguous;
 
+pub mod endian;
+
+pub mod hash;
+
+mod hex;
+
+mod include_pod;
+
+mod must_cast;
+
+mod option;
+
 mod pod;
 
+mod pod_hex;
+
+mod pod_view;
+
+mod reshape;
+
+mod resize;
+
 mod slice_fns;
 
+mod split;
+
+mod static_bytes;
+
+mod unaligned;
+
+mod unaligned_slice;
+
 pub mod wrapper;
 
 mod zeroable;
@@ -174,21 +225,70 @@ pub mod __priv_utils;
 
 #[doc(no_inline)]
 pub use bytemuck::{
-    self, AnyBitPattern, Contiguous, NoUninit, Pod, PodCastError, TransparentWrapper, Zeroable,
+    self, AnyBitPattern, Contiguous, NoUninit, Pod, PodCastError, PodInOption, TransparentWrapper,
+    Zeroable, ZeroableInOption,
 };
 
 pub use crate::{
+    aligned::{
+        cast_slice_from_aligned, from_bytes_aligned, try_cast_slice_from_aligned,
+        try_from_bytes_aligned, Align1, Align16, Align2, Align32, Align4, Align64, Align8, Aligned,
+        Alignment,
+    },
+    byte_array::{from_byte_array, from_byte_array_ref, to_byte_array, to_byte_array_ref},
+    byte_array_writer::ByteArrayWriter,
+    byte_reader::{ByteReader, ByteReaderError},
+    compare::{is_zeroed, pod_cmp, pod_eq, slice_pod_eq},
+    hex::{to_hex_array, to_hex_array_upper},
+    must_cast::{must_cast, must_cast_ref, must_cast_slice},
+    option::{cast_from_option, cast_option, try_cast_from_option, try_cast_option, zeroed_option},
     pod::{
-        cast, cast_ref_alt, pod_read_unaligned, try_cast, try_cast_ref_alt, try_pod_read_unaligned,
+        cast, cast_ref_alt, from_bytes, pod_read_unaligned, try_cast, try_cast_ref_alt,
+        try_from_bytes, try_pod_read_unaligned,
+    },
+    pod_hex::PodHex,
+    pod_view::PodView,
+    reshape::{
+        flatten_ref, flatten_slice, reshape, reshape_ref, try_unflatten_slice, unflatten_slice,
+        ArrayOf, ReshapeInto,
+    },
+    resize::{cast_extend, cast_truncate, try_cast_extend, try_cast_truncate, Placement},
+    slice_fns::{
+        as_array, as_array_chunks, as_rarray_chunks, bytes_of, cast_slice_alt,
+        cast_slice_prefix_alt, cast_slice_suffix_alt, try_cast_slice_alt,
+        try_cast_slice_prefix_alt, try_cast_slice_suffix_alt,
     },
-    slice_fns::{bytes_of, cast_slice_alt, try_cast_slice_alt},
+    split::{join_pod, split_pod, split_pod_at, try_split_pod_at},
+    unaligned::{
+        cast_ref_unaligned, cast_slice_unaligned, try_cast_ref_unaligned, try_cast_slice_unaligned,
+        Unaligned,
+    },
+    unaligned_slice::{UnalignedSlice, UnalignedSliceIter, UnalignedSliceIterRev},
     zeroable::zeroed,
 };
 
+#[cfg(feature = "rust_1_83")]
+pub use crate::{
+    pod::{cast_mut, try_cast_mut},
+    slice_fns::{bytes_of_mut, cast_slice_mut_alt, try_cast_slice_mut_alt},
+};
+
 #[doc(hidden)]
 pub mod __ {
-    pub use core::mem::size_of;
+    pub use core::mem::{size_of, MaybeUninit};
     pub use core::ops::Range;
+    pub use core::ptr::addr_of;
+    pub use core::{compile_error, include_bytes};
+
+    pub use crate::byte_array_writer::size_of_val_pod;
+
+    pub use crate::hex::parse_hex;
+
+    pub use crate::include_pod::{
+        include_pod_ref, include_pod_slice, reorder_bytes, AlignedBytes, ByteOrderWord, Endian,
+    };
+
+    pub use crate::pod_hex::assert_pod_eq;
 }
 
 use constmuck_internal::const_panic;
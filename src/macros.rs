@@ -15,6 +15,24 @@ macro_rules! __check_size {
     ($from:ty, $to:ty) => {};
 }
 
+// checks that a `[$from]` of length `$from_len` is as large as a `[$to]` of length `$to_len`
+#[cfg(feature = "debug_checks")]
+macro_rules! __check_slice_size {
+    ($from:ty, $to:ty, $from_len:expr, $to_len:expr) => {
+        if core::mem::size_of::<$from>() * $from_len != core::mem::size_of::<$to>() * $to_len {
+            crate::__priv_utils::transmute_unequal_size_panic(
+                core::mem::size_of::<$from>() * $from_len,
+                core::mem::size_of::<$to>() * $to_len,
+            )
+        }
+    };
+}
+
+#[cfg(not(feature = "debug_checks"))]
+macro_rules! __check_slice_size {
+    ($from:ty, $to:ty, $from_len:expr, $to_len:expr) => {};
+}
+
 #[cfg(feature = "debug_checks")]
 macro_rules! __check_same_alignment {
     ($from:ty, $to:ty) => {
@@ -64,6 +82,12 @@ macro_rules! __priv_transmute_from_copy {
 // this is unsafe to use for the same reason that `transmute::<&$from, &$to>` is,
 // the types might not be compatible.
 macro_rules! __priv_transmute_ref {
+    // casts a `&[$from]` into a `&$to` that covers the same bytes
+    ([$from:ty], $to:ty, $reference:expr) => {{
+        let reference: &[$from] = $reference;
+        __check_slice_size! {$from, $to, reference.len(), 1}
+        &*(reference.as_ptr() as *const $to)
+    }};
     ($from:ty, $to:ty, $reference:expr) => {{
         __check_size! {$from, $to}
         &*($reference as *const $from as *const $to)
@@ -79,4 +103,23 @@ macro_rules! __priv_transmute_slice {
         __check_size! {$from, $to}
         &*($reference as *const [$from] as *const [$to])
     }};
+    // casts a `&[$from]` into a `&[$to]` of length `$len` that covers the same bytes
+    ($from:ty, $to:ty, $reference:expr, len = $len:expr) => {{
+        let reference: &[$from] = $reference;
+        let len: usize = $len;
+        __check_slice_size! {$from, $to, reference.len(), len}
+        core::slice::from_raw_parts(reference.as_ptr() as *const $to, len)
+    }};
+}
+
+// Cast mutable references with feature-enabled debug checks
+//
+// this is unsafe to use for the same reason that `transmute::<&mut $from, &mut $to>` is,
+// the types might not be compatible.
+#[cfg(feature = "rust_1_83")]
+macro_rules! __priv_transmute_mut {
+    ($from:ty, $to:ty, $reference:expr) => {{
+        __check_size! {$from, $to}
+        &mut *($reference as *mut $from as *mut $to)
+    }};
 }
@@ -0,0 +1,183 @@
+use core::{
+    marker::PhantomData,
+    mem::{align_of, size_of},
+};
+
+use bytemuck::{AnyBitPattern, NoUninit};
+
+// Associated constants that cause a compile-time error
+// when they're used with incompatible `T` and `U` type arguments.
+struct Cast<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> Cast<T, U> {
+    const ASSERT_SIZE_EQUAL: () = if size_of::<T>() != size_of::<U>() {
+        crate::__priv_utils::unequal_size_panic(size_of::<T>(), size_of::<U>())
+    };
+
+    const ASSERT_ALIGN_GREATER_THAN_EQUAL: () = if align_of::<T>() < align_of::<U>() {
+        crate::__priv_utils::incompatible_alignment_panic(align_of::<T>(), align_of::<U>())
+    };
+
+    const ASSERT_SIZE_MULTIPLE_OF_OR_BOTH_ZST: () =
+        if (size_of::<T>() == 0) != (size_of::<U>() == 0) {
+            crate::__priv_utils::slice_cast_zst_panic(size_of::<T>(), size_of::<U>())
+        } else if size_of::<U>() != 0 && size_of::<T>() % size_of::<U>() != 0 {
+            crate::__priv_utils::size_not_multiple_panic(size_of::<T>(), size_of::<U>())
+        };
+}
+
+/// Casts `T` into `U`, erroring at compile-time if the sizes don't match.
+///
+/// This is the compile-time-checked equivalent of [`cast`](crate::cast).
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `T` is not the same size as `U`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::must_cast;
+///
+/// const LE_BYTES: [u8; 4] = must_cast(0xAB1E_BEEF_u32.to_le());
+///
+/// assert_eq!(LE_BYTES, 0xAB1E_BEEF_u32.to_le_bytes());
+///
+/// ```
+///
+/// Casting between types of different sizes is an error:
+///
+/// ```compile_fail
+/// const BYTES: [u8; 2] = constmuck::must_cast(0u32);
+/// ```
+#[inline(always)]
+pub const fn must_cast<T, U>(from: T) -> U
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    let _: () = Cast::<T, U>::ASSERT_SIZE_EQUAL;
+
+    // safety: the bounds guarantee that both `T` and `U`
+    // contain no padding and are valid for all bitpatterns.
+    // They are both guaranteed to be the same size by the above assertion.
+    unsafe { __priv_transmute!(T, U, from) }
+}
+
+/// Casts `&T` to `&U`, erroring at compile-time if the sizes or alignments
+/// aren't compatible.
+///
+/// This is the compile-time-checked equivalent of [`cast_ref_alt`](crate::cast_ref_alt).
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error in these cases:
+/// - The alignment of `T` is lower than `U`
+/// - The size of `T` is not equal to `U`
+///
+/// Because the checks happen after monomorphization,
+/// `cargo check` might not report the errors, but `cargo build` does.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::must_cast_ref;
+///
+/// const U8: &[u8; 2] = must_cast_ref(&100u16.to_le());
+///
+/// assert_eq!(U8, &[100u8, 0]);
+///
+/// ```
+///
+/// Casting to a more aligned type is an error:
+///
+/// ```compile_fail
+/// const NUM: &u16 = constmuck::must_cast_ref(&[3u8, 5]);
+/// ```
+#[inline(always)]
+pub const fn must_cast_ref<T, U>(from: &T) -> &U
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    let _: () = Cast::<T, U>::ASSERT_SIZE_EQUAL;
+    let _: () = Cast::<T, U>::ASSERT_ALIGN_GREATER_THAN_EQUAL;
+
+    // safety: the bounds guarantee that both `T` and `U`
+    // contain no padding and are valid for all bitpatterns.
+    //
+    // The above assertions guarantee that they're the same size,
+    // and that T is at least as aligned as U.
+    unsafe { __priv_transmute_ref!(T, U, from) }
+}
+
+/// Casts `&[T]` to `&[U]`, erroring at compile-time if the sizes or alignments
+/// aren't compatible.
+///
+/// This is the compile-time-checked equivalent of [`cast_slice_alt`](crate::cast_slice_alt),
+/// the length of the returned slice is `from.len() * size_of::<T>() / size_of::<U>()`.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error in these cases:
+/// - The alignment of `T` is lower than `U`
+/// - The size of `T` is not a multiple of `U`'s size
+/// - Only one of `T` and `U` is zero-sized
+///
+/// Because the checks happen after monomorphization,
+/// `cargo check` might not report the errors, but `cargo build` does.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::must_cast_slice;
+///
+/// const PAIRS: &[[u8; 2]] = must_cast_slice(&[[3u8, 5, 8, 13], [21, 34, 55, 89]]);
+///
+/// assert_eq!(*PAIRS, [[3, 5], [8, 13], [21, 34], [55, 89]]);
+///
+/// ```
+///
+/// Casting to an element type that the input element type's size isn't
+/// a multiple of is an error:
+///
+/// ```compile_fail
+/// const TRIPLES: &[[u8; 3]] = constmuck::must_cast_slice(&[[3u8, 5], [8, 13]]);
+/// ```
+///
+/// The checks happen at compile-time even when the function is called at runtime,
+/// so casting between zero-sized and non-zero-sized types is an error here:
+///
+/// ```compile_fail
+/// fn to_bytes(units: &[()]) -> &[u8] {
+///     constmuck::must_cast_slice(units)
+/// }
+///
+/// to_bytes(&[(); 3]);
+/// ```
+#[inline(always)]
+pub const fn must_cast_slice<T, U>(from: &[T]) -> &[U]
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    let _: () = Cast::<T, U>::ASSERT_SIZE_MULTIPLE_OF_OR_BOTH_ZST;
+    let _: () = Cast::<T, U>::ASSERT_ALIGN_GREATER_THAN_EQUAL;
+
+    let new_len = if size_of::<T>() == size_of::<U>() {
+        from.len()
+    } else {
+        from.len() * (size_of::<T>() / size_of::<U>())
+    };
+
+    // safety: the bounds guarantee that both `T` and `U`
+    // contain no padding and are valid for all bitpatterns.
+    //
+    // The above assertions guarantee that T is at least as aligned as U,
+    // and that `from.len() * size_of::<T>() == new_len * size_of::<U>()`
+    // (when `T` is zero-sized, `U` is too, and `new_len == from.len()`).
+    unsafe { core::slice::from_raw_parts(from.as_ptr().cast::<U>(), new_len) }
+}
@@ -0,0 +1,193 @@
+use core::mem::size_of;
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError, PodInOption, ZeroableInOption};
+
+/// Casts `T` into `Option<U>`
+///
+/// [`PodInOption`] guarantees that `Option<U>` is valid for any bit pattern,
+/// and that the all-zeroes bit pattern is `None`.
+///
+/// # Panics
+///
+/// This panics if `T` is not the same size as `Option<U>`
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::cast_option;
+///
+/// use std::num::NonZeroU32;
+///
+/// const HANDLES: [Option<NonZeroU32>; 3] = [cast_option(0u32), cast_option(7u32), cast_option(-1i32)];
+///
+/// assert_eq!(HANDLES, [None, NonZeroU32::new(7), NonZeroU32::new(u32::MAX)]);
+///
+/// ```
+#[track_caller]
+pub const fn cast_option<T, U>(from: T) -> Option<U>
+where
+    T: NoUninit,
+    U: PodInOption,
+{
+    match try_cast_option(from) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_size_panic(size_of::<T>(), size_of::<Option<U>>())
+        }
+    }
+}
+
+/// Tries to cast `T` into `Option<U>`
+///
+/// [`PodInOption`] guarantees that `Option<U>` is valid for any bit pattern,
+/// and that the all-zeroes bit pattern is `None`.
+///
+/// # Errors
+///
+/// This returns an `Err(PodCastError::SizeMismatch)`
+/// when `T` isn't the same size as `Option<U>`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_option, PodCastError};
+///
+/// use std::num::NonZeroU16;
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const SOME: Res<Option<NonZeroU16>> = try_cast_option(3u16);
+/// const NONE: Res<Option<NonZeroU16>> = try_cast_option([0u8; 2]);
+/// const ERR: Res<Option<NonZeroU16>> = try_cast_option(3u32);
+///
+/// assert_eq!(SOME, Ok(NonZeroU16::new(3)));
+/// assert_eq!(NONE, Ok(None));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_option<T, U>(from: T) -> Result<Option<U>, PodCastError>
+where
+    T: NoUninit,
+    U: PodInOption,
+{
+    if size_of::<T>() == size_of::<Option<U>>() {
+        // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        //         `U: PodInOption` guarantees that `Option<U>` is valid for any bit pattern,
+        //         and they're the same size in this branch.
+        unsafe { Ok(__priv_transmute!(T, Option<U>, from)) }
+    } else {
+        Err(PodCastError::SizeMismatch)
+    }
+}
+
+/// Casts `Option<T>` into `U`
+///
+/// [`PodInOption`] guarantees that `Option<T>` contains no uninitialized bytes,
+/// and that `None` is represented as all zeroes.
+///
+/// # Panics
+///
+/// This panics if `Option<T>` is not the same size as `U`
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::cast_from_option;
+///
+/// use std::num::NonZeroU32;
+///
+/// const RAW: [u32; 2] = [cast_from_option(NonZeroU32::new(5)), cast_from_option(None::<NonZeroU32>)];
+///
+/// assert_eq!(RAW, [5, 0]);
+///
+/// ```
+#[track_caller]
+pub const fn cast_from_option<T, U>(from: Option<T>) -> U
+where
+    T: PodInOption,
+    U: AnyBitPattern,
+{
+    match try_cast_from_option(from) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_size_panic(size_of::<Option<T>>(), size_of::<U>())
+        }
+    }
+}
+
+/// Tries to cast `Option<T>` into `U`
+///
+/// [`PodInOption`] guarantees that `Option<T>` contains no uninitialized bytes,
+/// and that `None` is represented as all zeroes.
+///
+/// # Errors
+///
+/// This returns an `Err(PodCastError::SizeMismatch)`
+/// when `Option<T>` isn't the same size as `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_from_option, PodCastError};
+///
+/// use std::num::NonZeroI8;
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const SOME: Res<u8> = try_cast_from_option(NonZeroI8::new(-1));
+/// const NONE: Res<i8> = try_cast_from_option(None::<NonZeroI8>);
+/// const ERR: Res<u16> = try_cast_from_option(NonZeroI8::new(-1));
+///
+/// assert_eq!(SOME, Ok(255));
+/// assert_eq!(NONE, Ok(0));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_from_option<T, U>(from: Option<T>) -> Result<U, PodCastError>
+where
+    T: PodInOption,
+    U: AnyBitPattern,
+{
+    if size_of::<Option<T>>() == size_of::<U>() {
+        // SAFETY: `T: PodInOption` guarantees that `Option<T>` contains no uninitialized bytes,
+        //         `U: AnyBitPattern` guarantees that `U` is valid for any bit pattern,
+        //         and they're the same size in this branch.
+        unsafe { Ok(__priv_transmute!(Option<T>, U, from)) }
+    } else {
+        Err(PodCastError::SizeMismatch)
+    }
+}
+
+/// Constructs a zero-initialized `Option<T>`.
+///
+/// [`ZeroableInOption`] guarantees that the all-zeroes `Option<T>` is `None`,
+/// so this always returns `None`.
+/// This function exists for when a zeroed `Option<T>` is needed for any `T` that
+/// implements [`ZeroableInOption`], including references and function pointers.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::zeroed_option;
+///
+/// struct Handlers {
+///     on_open: Option<fn(u32) -> bool>,
+///     on_close: Option<fn()>,
+///     name: Option<&'static str>,
+/// }
+///
+/// const EMPTY: Handlers = Handlers {
+///     on_open: zeroed_option(),
+///     on_close: zeroed_option(),
+///     name: zeroed_option(),
+/// };
+///
+/// assert!(EMPTY.on_open.is_none());
+/// assert!(EMPTY.on_close.is_none());
+/// assert!(EMPTY.name.is_none());
+///
+/// ```
+#[inline(always)]
+pub const fn zeroed_option<T: ZeroableInOption>() -> Option<T> {
+    None
+}
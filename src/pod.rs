@@ -2,7 +2,7 @@ use core::mem;
 
 use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
 
-use crate::__priv_utils::Packed;
+use crate::unaligned::Unaligned;
 
 /// Casts `T` into `U`
 ///
@@ -219,11 +219,242 @@ pub const fn pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> T {
 pub const fn try_pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> Result<T, PodCastError> {
     if core::mem::size_of::<T>() == bytes.len() {
         // SAFETY: the slice is as large as `T`,
-        //         and `Packed` does not have alignment requirements.
-        let packed = unsafe { *bytes.as_ptr().cast::<Packed<T>>() };
-
-        Ok(packed.0)
+        //         and `T: AnyBitPattern` is valid for any bit pattern.
+        unsafe { Ok(read_unaligned_unchecked(bytes)) }
     } else {
         Err(PodCastError::SizeMismatch)
     }
 }
+
+// Reads a `T` out of the first `size_of::<T>()` bytes of `bytes`.
+//
+// # Safety
+//
+// `bytes.len()` must be at least `size_of::<T>()`,
+// and `T` must be valid for any bit pattern.
+pub(crate) const unsafe fn read_unaligned_unchecked<T: Copy>(bytes: &[u8]) -> T {
+    // `Unaligned` does not have alignment requirements.
+    let unaligned = *bytes.as_ptr().cast::<Unaligned<T>>();
+
+    unaligned.get()
+}
+
+/// Reinterprets a byte slice as a `&T`, without copying.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_from_bytes`]
+/// returns [an error](crate::try_from_bytes#errors).
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment of `1`.
+/// [`bytemuck::from_bytes`] allows `T` to have a larger alignment,
+/// so long as the `bytes` slice happens to be aligned to `T`.
+/// The address of a slice can't be inspected in const fns,
+/// so constmuck can't check whether the slice happens to be aligned.
+///
+/// To reinterpret bytes as a more aligned type,
+/// you can store the bytes in an [`Aligned`](crate::Aligned),
+/// and use [`from_bytes_aligned`](crate::from_bytes_aligned).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{from_bytes, AnyBitPattern};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Rgb {
+///     red: u8,
+///     green: u8,
+///     blue: u8,
+/// }
+///
+/// const COLOR: &Rgb = from_bytes(&[3, 5, 8]);
+///
+/// assert_eq!(COLOR, &Rgb{red: 3, green: 5, blue: 8});
+///
+/// ```
+#[track_caller]
+pub const fn from_bytes<T: AnyBitPattern>(bytes: &[u8]) -> &T {
+    match try_from_bytes(bytes) {
+        Ok(x) => x,
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
+            crate::__priv_utils::bytes_incompatible_alignment_panic(mem::align_of::<T>())
+        }
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_bytes_size_panic(bytes.len(), mem::size_of::<T>())
+        }
+    }
+}
+
+/// Tries to reinterpret a byte slice as a `&T`, without copying.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - `size_of::<T>() != bytes.len()`, returning a
+///   `Err(PodCastError::SizeMismatch)`.
+///
+/// - The alignment of `T` is larger than `1`, returning a
+///   `Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)`.
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment of `1`.
+/// [`bytemuck::try_from_bytes`] allows `T` to have a larger alignment,
+/// so long as the `bytes` slice happens to be aligned to `T`.
+/// The address of a slice can't be inspected in const fns,
+/// so constmuck can't check whether the slice happens to be aligned.
+///
+/// To reinterpret bytes as a more aligned type,
+/// you can store the bytes in an [`Aligned`](crate::Aligned),
+/// and use [`try_from_bytes_aligned`](crate::try_from_bytes_aligned).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_from_bytes, PodCastError};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const PAIR: Res<&[u8; 2]> = try_from_bytes(&[3, 5]);
+/// const ERR_SIZE: Res<&[u8; 2]> = try_from_bytes(&[3, 5, 8]);
+/// const ERR_ALIGN: Res<&u16> = try_from_bytes(&[3, 5]);
+///
+/// assert_eq!(PAIR, Ok(&[3, 5]));
+/// assert_eq!(ERR_SIZE, Err(PodCastError::SizeMismatch));
+/// assert_eq!(ERR_ALIGN, Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned));
+///
+/// ```
+pub const fn try_from_bytes<T: AnyBitPattern>(bytes: &[u8]) -> Result<&T, PodCastError> {
+    if mem::size_of::<T>() != bytes.len() {
+        Err(PodCastError::SizeMismatch)
+    } else if mem::align_of::<T>() != 1 {
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    } else {
+        // SAFETY: `T: AnyBitPattern` guarantees that `T` is valid for any bit pattern,
+        //         the slice is as large as `T`, and `T` has no alignment requirements.
+        unsafe { Ok(&*bytes.as_ptr().cast::<T>()) }
+    }
+}
+
+/// Casts `&mut T` to `&mut U`
+///
+/// Requires the `"rust_1_83"` feature.
+///
+/// # Panics
+///
+/// This function panics in these cases:
+/// - The alignment of `T` is larger than `U`
+/// - The size of `T` is not equal to `U`
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::cast_mut`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::cast_mut;
+///
+/// const ARR: [u16; 2] = {
+///     let mut arr = [0u16; 2];
+///     let bytes: &mut [u8; 4] = cast_mut(&mut arr);
+///     bytes[0] = 3;
+///     bytes[3] = 5;
+///     arr
+/// };
+///
+/// assert_eq!(ARR, [u16::from_ne_bytes([3, 0]), u16::from_ne_bytes([0, 5])]);
+///
+/// ```
+#[cfg(feature = "rust_1_83")]
+#[track_caller]
+pub const fn cast_mut<T, U>(from: &mut T) -> &mut U
+where
+    T: NoUninit + AnyBitPattern,
+    U: NoUninit + AnyBitPattern,
+{
+    match try_cast_mut(from) {
+        Ok(x) => x,
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
+            crate::__priv_utils::incompatible_alignment_panic(
+                mem::align_of::<T>(),
+                mem::align_of::<U>(),
+            )
+        }
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_size_panic(mem::size_of::<T>(), mem::size_of::<U>())
+        }
+    }
+}
+
+/// Tries to cast `&mut T` to `&mut U`
+///
+/// Requires the `"rust_1_83"` feature.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The alignment of `T` is larger than `U`, returning a
+///   `Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)`.
+///
+/// - The size of `T` is not equal to `U`, returning a
+///   `Err(PodCastError::SizeMismatch)`.
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::try_cast_mut`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::PodCastError;
+/// use constmuck::try_cast_mut;
+///
+/// const RES: (u32, Result<(), PodCastError>) = {
+///     let mut num = 0u32;
+///
+///     let err = match try_cast_mut::<u32, u16>(&mut num) {
+///         Ok(_) => Ok(()),
+///         Err(e) => Err(e),
+///     };
+///
+///     if let Ok(x) = try_cast_mut::<u32, i32>(&mut num) {
+///         *x = -1;
+///     }
+///
+///     (num, err)
+/// };
+///
+/// assert_eq!(RES, (u32::MAX, Err(PodCastError::SizeMismatch)));
+///
+/// ```
+#[cfg(feature = "rust_1_83")]
+pub const fn try_cast_mut<T, U>(from: &mut T) -> Result<&mut U, PodCastError>
+where
+    T: NoUninit + AnyBitPattern,
+    U: NoUninit + AnyBitPattern,
+{
+    unsafe {
+        if mem::align_of::<T>() < mem::align_of::<U>() {
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+        } else if mem::size_of::<T>() != mem::size_of::<U>() {
+            Err(PodCastError::SizeMismatch)
+        } else {
+            // safety: the bounds guarantee that both `T` and `U`
+            // contain no padding and are valid for all bitpatterns,
+            // so writes through the returned reference can't produce an invalid `T`.
+            //
+            // They are both guaranteed the same size in this branch,
+            // and T is at least as aligned as U.
+            Ok(__priv_transmute_mut!(T, U, from))
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use core::fmt::{self, Debug};
+
+use bytemuck::NoUninit;
+
+use crate::{
+    __priv_utils::pod_ne_panic,
+    const_panic::{FmtArg as FA, IsCustomType, PanicFmt, PanicVal as PV},
+    slice_fns::bytes_of,
+};
+
+/// Formats the bytes of a [`NoUninit`] value as hexadecimal,
+/// both in const panics (with [`const_panic`]) and with [`Debug`].
+///
+/// The bytes are those returned by [`bytes_of`](crate::bytes_of),
+/// formatted like `{:X?}` formats a `&[u8]`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::PodHex;
+///
+/// use const_panic::concat_panic;
+///
+/// const fn check_magic(magic: &[u8; 4]) {
+///     if magic[0] != 0x7F {
+///         concat_panic!("invalid magic number: ", PodHex::new(magic))
+///     }
+/// }
+///
+/// const _: () = check_magic(b"\x7FELF");
+///
+/// assert_eq!(format!("{:?}", PodHex::new(b"\x7FELF")), "[7F, 45, 4C, 46]");
+///
+/// let err = std::panic::catch_unwind(|| check_magic(b"\x00\x0A\xFF\x10")).unwrap_err();
+/// assert_eq!(
+///     err.downcast_ref::<String>().unwrap(),
+///     "invalid magic number: [0, A, FF, 10]",
+/// );
+///
+/// ```
+///
+/// [`const_panic`]: https://docs.rs/const_panic/0.2/const_panic/index.html
+pub struct PodHex<'a, T> {
+    value: &'a T,
+}
+
+impl<'a, T> Copy for PodHex<'a, T> {}
+
+impl<'a, T> Clone for PodHex<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: NoUninit> PodHex<'a, T> {
+    /// Constructs a `PodHex` that formats the bytes of `value`.
+    pub const fn new(value: &'a T) -> Self {
+        Self { value }
+    }
+
+    /// Gets the wrapped reference.
+    pub const fn get(self) -> &'a T {
+        self.value
+    }
+
+    /// Gets the bytes that this formats.
+    pub const fn bytes(self) -> &'a [u8] {
+        bytes_of(self.value)
+    }
+
+    /// Formats the bytes of the value into a single-element `PanicVal` array.
+    ///
+    /// This is used by the [`const_panic`] macros.
+    ///
+    /// [`const_panic`]: https://docs.rs/const_panic/0.2/const_panic/index.html
+    pub const fn to_panicvals(&self, fmtarg: FA) -> [PV<'a>; 1] {
+        [self.to_panicval(fmtarg)]
+    }
+
+    /// Formats the bytes of the value into a `PanicVal`.
+    pub const fn to_panicval(&self, fmtarg: FA) -> PV<'a> {
+        PV::from_slice_u8(bytes_of(self.value), fmtarg.set_hex())
+    }
+}
+
+impl<'a, T: NoUninit> PanicFmt for PodHex<'a, T> {
+    type This = Self;
+    type Kind = IsCustomType;
+    const PV_COUNT: usize = 1;
+}
+
+impl<'a, T: NoUninit> Debug for PodHex<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X?}", bytes_of(self.value))
+    }
+}
+
+/// Asserts that the bytes of two [`NoUninit`] values are equal,
+/// panicking with the offset of the first byte that differs otherwise.
+///
+/// This compares the values like [`pod_eq`](crate::pod_eq) does,
+/// and formats them like [`PodHex`] does,
+/// both arguments must be of the same type.
+///
+/// This macro can be used in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::assert_pod_eq;
+///
+/// const TABLE: [u16; 3] = [3, 5, 8];
+///
+/// const _: () = assert_pod_eq!(TABLE, [3u16, 5, 8]);
+///
+/// let err = std::panic::catch_unwind(|| assert_pod_eq!(*b"hello", *b"help!")).unwrap_err();
+/// assert_eq!(
+///     err.downcast_ref::<String>().unwrap(),
+///     concat!(
+///         "\nassertion failed: the bytes of `left` and `right` are not equal",
+///         "\nfirst differing byte offset: 3",
+///         "\n left: [68, 65, 6C, 6C, 6F]",
+///         "\nright: [68, 65, 6C, 70, 21]",
+///     ),
+/// );
+///
+/// ```
+#[macro_export]
+macro_rules! assert_pod_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__::assert_pod_eq(&$left, &$right)
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn assert_pod_eq<T: NoUninit>(left: &T, right: &T) {
+    let lbytes = bytes_of(left);
+    let rbytes = bytes_of(right);
+
+    let mut i = 0;
+    while i < lbytes.len() {
+        if lbytes[i] != rbytes[i] {
+            pod_ne_panic(i, PodHex::new(left), PodHex::new(right))
+        }
+        i += 1;
+    }
+}
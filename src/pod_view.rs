@@ -0,0 +1,289 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use bytemuck::{AnyBitPattern, PodCastError};
+
+use crate::{
+    __priv_utils::{subslice, view_too_short_panic},
+    pod::read_unaligned_unchecked,
+};
+
+/// A view of a `T` stored in a byte slice,
+/// which doesn't require the bytes to be aligned for `T`.
+///
+/// The `T` is read by value with the [`get`](Self::get) method,
+/// and views of the fields of `T` can be gotten with
+/// the [`view_field`](crate::view_field) macro.
+///
+/// The bytes are read with the same layout as
+/// [`pod_read_unaligned`](crate::pod_read_unaligned),
+/// which means that integers are read in native endianness.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{view_field, AnyBitPattern, PodView};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Record {
+///     id: [u8; 2],
+///     len: u16,
+///     pos: Point,
+/// }
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Point {
+///     x: u16,
+///     y: u16,
+/// }
+///
+/// // the record starts at an odd offset, so it's not aligned for `u16`
+/// const BUFFER: &[u8] = b"\xFFAB\x03\x00\x05\x00\x08\x00";
+///
+/// const VIEW: PodView<'_, Record> = PodView::new(match BUFFER {
+///     [_header, record @ ..] => record,
+///     [] => &[],
+/// });
+///
+/// const ID: [u8; 2] = view_field!(VIEW, Record, id).get();
+/// const Y: u16 = view_field!(view_field!(VIEW, Record, pos), Point, y).get();
+///
+/// assert_eq!(ID, *b"AB");
+/// assert_eq!(Y, 8u16.to_le());
+///
+/// assert_eq!(
+///     VIEW.get(),
+///     Record {
+///         id: *b"AB",
+///         len: 3u16.to_le(),
+///         pos: Point { x: 5u16.to_le(), y: 8u16.to_le() },
+///     },
+/// );
+///
+/// ```
+pub struct PodView<'a, T> {
+    // invariant: `bytes.len() == size_of::<T>()`
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Copy for PodView<'a, T> {}
+
+impl<'a, T> Clone for PodView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: AnyBitPattern> PodView<'a, T> {
+    /// Constructs a view of the `T` at the start of `bytes`.
+    ///
+    /// The bytes after the first `size_of::<T>()` bytes are ignored.
+    ///
+    /// # Panics
+    ///
+    /// This function panics in the cases where [`try_new`](Self::try_new)
+    /// returns [an error](Self::try_new).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::PodView;
+    ///
+    /// const VIEW: PodView<'_, [u8; 2]> = PodView::new(&[3, 5, 8]);
+    ///
+    /// assert_eq!(VIEW.get(), [3, 5]);
+    /// assert_eq!(VIEW.as_bytes(), [3, 5]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        match Self::try_new(bytes) {
+            Ok(x) => x,
+            Err(PodCastError::SizeMismatch | _) => {
+                view_too_short_panic(bytes.len(), size_of::<T>())
+            }
+        }
+    }
+
+    /// Constructs a view of the `T` at the start of `bytes`.
+    ///
+    /// The bytes after the first `size_of::<T>()` bytes are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This returns `Err(PodCastError::SizeMismatch)` if
+    /// `bytes.len() < size_of::<T>()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{PodCastError, PodView};
+    ///
+    /// const OK: Option<u32> = match PodView::<u32>::try_new(&[0; 4]) {
+    ///     Ok(view) => Some(view.get()),
+    ///     Err(_) => None,
+    /// };
+    /// assert_eq!(OK, Some(0));
+    ///
+    /// assert!(matches!(PodView::<u32>::try_new(&[0; 3]), Err(PodCastError::SizeMismatch)));
+    ///
+    /// ```
+    pub const fn try_new(bytes: &'a [u8]) -> Result<Self, PodCastError> {
+        if bytes.len() < size_of::<T>() {
+            return Err(PodCastError::SizeMismatch);
+        }
+
+        Ok(Self {
+            bytes: subslice(bytes, 0, size_of::<T>()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Reads the viewed `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::PodView;
+    ///
+    /// const NUM: u32 = PodView::new(&[0x01, 0x02, 0x03, 0x04]).get();
+    ///
+    /// assert_eq!(NUM, u32::from_ne_bytes([1, 2, 3, 4]));
+    ///
+    /// ```
+    pub const fn get(self) -> T {
+        // SAFETY: `self.bytes` is as large as `T`,
+        //         and `T: AnyBitPattern` is valid for any bit pattern.
+        unsafe { read_unaligned_unchecked(self.bytes) }
+    }
+
+    /// The bytes of the viewed `T`, this is always `size_of::<T>()` bytes long.
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    // Used by `view_field` to get the view of a field of `T`
+    //
+    // # Safety
+    //
+    // `field` must point to a field of the `T` that `base` points to,
+    // fields of types that `T` dereferences to aren't allowed.
+    #[doc(hidden)]
+    #[track_caller]
+    pub const unsafe fn __project_field<F>(self, base: *const T, field: *const F) -> PodView<'a, F>
+    where
+        F: AnyBitPattern,
+    {
+        let offset = field.cast::<u8>().offset_from(base.cast::<u8>()) as usize;
+
+        PodView {
+            bytes: subslice(self.bytes, offset, offset + size_of::<F>()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: AnyBitPattern + Debug> Debug for PodView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PodView").field(&self.get()).finish()
+    }
+}
+
+/// Gets a [`PodView`] of a field of the `T` viewed by a `PodView<'_, T>`.
+///
+/// This macro takes the view, the `T` type, and the field,
+/// which can be a named field or a tuple index.
+/// The type of the field must implement [`AnyBitPattern`](crate::AnyBitPattern).
+///
+/// The `T` type can't be inferred from the view,
+/// because it's needed to check that the field is declared by `T`.
+/// Without that check, a field of the type that `T` dereferences to
+/// would be accessed by calling `Deref::deref` on a `T` that doesn't exist.
+///
+/// This macro can be used in const contexts.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the field isn't declared by `T` itself,
+/// this includes fields that are only accessible through a `Deref` impl of `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{view_field, AnyBitPattern, PodView};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, AnyBitPattern)]
+/// struct Header {
+///     magic: [u8; 4],
+///     sizes: Sizes,
+/// }
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, AnyBitPattern)]
+/// struct Sizes(u16, u16);
+///
+/// const BYTES: &[u8] = b"WAD!\x10\x00\x20\x00";
+///
+/// const SIZES: PodView<'_, Sizes> = view_field!(PodView::new(BYTES), Header, sizes);
+///
+/// assert_eq!(view_field!(SIZES, Sizes, 0).get(), 0x10u16.to_le());
+/// assert_eq!(view_field!(SIZES, Sizes, 1).get(), 0x20u16.to_le());
+///
+/// ```
+///
+/// Fields that are only accessible through `Deref` are an error:
+///
+/// ```compile_fail
+/// use constmuck::{view_field, AnyBitPattern, PodView};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, AnyBitPattern)]
+/// struct Wrapper(u32);
+///
+/// impl std::ops::Deref for Wrapper {
+///     type Target = Inner;
+///
+///     fn deref(&self) -> &Inner {
+///         &Inner { field: 0 }
+///     }
+/// }
+///
+/// struct Inner {
+///     field: u8,
+/// }
+///
+/// let view = PodView::<Wrapper>::new(&[0; 4]);
+/// let _ = view_field!(view, Wrapper, field);
+/// ```
+#[macro_export]
+macro_rules! view_field {
+    ($view:expr, $ty:path, $field:tt $(,)?) => {
+        match $view {
+            view => {
+                // ensures that the field is declared by the struct,
+                // rather than being accessed through `Deref`
+                #[allow(clippy::unneeded_field_pattern)]
+                let $ty { $field: _, .. };
+
+                // only the address of the field is needed, so the `T` isn't read
+                let uninit = $crate::__::MaybeUninit::<$ty>::uninit();
+                let base: *const $ty = uninit.as_ptr();
+
+                // SAFETY: `addr_of` doesn't read from the field,
+                //         and `base` points to an allocation as large as `$ty`.
+                let field = unsafe { $crate::__::addr_of!((*base).$field) };
+
+                // SAFETY: `field` points to a field of `*base`,
+                //         since the field is declared by the `$ty` struct.
+                unsafe { view.__project_field(base, field) }
+            }
+        }
+    };
+}
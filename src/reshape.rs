@@ -0,0 +1,323 @@
+use core::marker::PhantomData;
+
+use bytemuck::PodCastError;
+
+use crate::__priv_utils::{reshape_unequal_len_panic, unflatten_slop_panic};
+
+/// Trait for arrays (including nested arrays) of `T`.
+///
+/// This trait is implemented for `[T; A]`, `[[T; A]; B]`, and `[[[T; A]; B]; C]`,
+/// which all have the same layout as a `[T; Self::LEN]`.
+/// Arrays nested more than three levels deep are only supported
+/// by treating the innermost arrays as the `T`.
+///
+/// This trait can only be implemented in `constmuck`.
+pub trait ArrayOf<T>: sealed::Sealed<T> {
+    /// The amount of `T`s in `Self`
+    const LEN: usize;
+}
+
+/// Trait for arrays that can be reshaped into `To`,
+/// with [`reshape`] and [`reshape_ref`].
+///
+/// Both `Self` and `To` are [arrays of](ArrayOf) the [`Elem`](Self::Elem) type,
+/// nested up to three levels deep.
+///
+/// This trait is implemented for these combinations of `Self` and `To`:
+/// - `[[T; A]; B]` and `[[T; C]; D]`
+/// - `[T; A]` and `[[T; B]; C]`
+/// - `[[T; A]; B]` and `[T; C]`
+/// - `[T; A]` and `[[[T; B]; C]; D]`
+/// - `[[[T; A]; B]; C]` and `[T; D]`
+///
+/// Other combinations are only supported if the innermost arrays of both
+/// `Self` and `To` are the same type,
+/// e.g.: `[[[T; 2]; 3]; 4]` can be reshaped into `[[T; 2]; 12]`,
+/// but it can't be reshaped into `[[T; 3]; 8]`.
+///
+/// This trait can only be implemented in `constmuck`.
+pub trait ReshapeInto<To>: sealed::ReshapeSealed<To> {
+    /// The type of the elements that both `Self` and `To` are arrays of.
+    type Elem;
+
+    /// The amount of `Elem`s in `Self`
+    const FROM_LEN: usize;
+
+    /// The amount of `Elem`s in `To`
+    const TO_LEN: usize;
+}
+
+mod sealed {
+    pub trait Sealed<T> {}
+
+    pub trait ReshapeSealed<To> {}
+}
+
+impl<T, const A: usize> sealed::Sealed<T> for [T; A] {}
+impl<T, const A: usize> ArrayOf<T> for [T; A] {
+    const LEN: usize = A;
+}
+
+impl<T, const A: usize, const B: usize> sealed::Sealed<T> for [[T; A]; B] {}
+impl<T, const A: usize, const B: usize> ArrayOf<T> for [[T; A]; B] {
+    const LEN: usize = A * B;
+}
+
+impl<T, const A: usize, const B: usize, const C: usize> sealed::Sealed<T> for [[[T; A]; B]; C] {}
+impl<T, const A: usize, const B: usize, const C: usize> ArrayOf<T> for [[[T; A]; B]; C] {
+    const LEN: usize = A * B * C;
+}
+
+// There's one impl for each difference in depth between `From` and `To`,
+// because impls where both `From` and `To` are one level deeper would overlap.
+macro_rules! impl_reshape_into {
+    ($(
+        [$($const:ident),*]
+        $from:ty => $to:ty;
+    )*) => {$(
+        impl<T, $(const $const: usize),*> sealed::ReshapeSealed<$to> for $from {}
+
+        impl<T, $(const $const: usize),*> ReshapeInto<$to> for $from {
+            type Elem = T;
+
+            const FROM_LEN: usize = <$from as ArrayOf<T>>::LEN;
+
+            const TO_LEN: usize = <$to as ArrayOf<T>>::LEN;
+        }
+    )*};
+}
+
+impl_reshape_into! {
+    [A, B, C, D] [[T; A]; B] => [[T; C]; D];
+    [A, B, C] [T; A] => [[T; B]; C];
+    [A, B, C] [[T; A]; B] => [T; C];
+    [A, B, C, D] [T; A] => [[[T; B]; C]; D];
+    [A, B, C, D] [[[T; A]; B]; C] => [T; D];
+}
+
+// Associated constant that causes a compile-time error
+// when `From` and `To` don't have the same amount of elements.
+struct Reshape<From, To>(PhantomData<(From, To)>);
+
+impl<From, To> Reshape<From, To>
+where
+    From: ReshapeInto<To>,
+{
+    const ASSERT_SAME_LEN: () = if From::FROM_LEN != From::TO_LEN {
+        reshape_unequal_len_panic(From::FROM_LEN, From::TO_LEN)
+    };
+}
+
+/// Converts between arrays with the same amount of elements,
+/// erroring at compile-time if the amounts differ.
+///
+/// The arrays can be nested up to three levels deep,
+/// [`ReshapeInto`] describes which combinations of arrays are supported.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `From` and `To`
+/// don't have the same amount of elements.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::reshape;
+///
+/// const MATRIX: [[f32; 3]; 2] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+///
+/// const FLAT: [f32; 6] = reshape::<[[f32; 3]; 2], [f32; 6]>(MATRIX);
+/// assert_eq!(FLAT, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+///
+/// const PAIRS: [[f32; 2]; 3] = reshape(MATRIX);
+/// assert_eq!(PAIRS, [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+///
+/// ```
+#[inline(always)]
+pub const fn reshape<From, To>(from: From) -> To
+where
+    From: ReshapeInto<To>,
+{
+    let _: () = Reshape::<From, To>::ASSERT_SAME_LEN;
+
+    // safety: `ReshapeInto<To>` guarantees that both `From` and `To` are
+    //         arrays of `Elem`s, with the same layout as `[Elem; LEN]`,
+    //         and the above assertion ensures that both `LEN`s are equal.
+    unsafe { __priv_transmute!(From, To, from) }
+}
+
+/// Casts between references to arrays with the same amount of elements,
+/// erroring at compile-time if the amounts differ.
+///
+/// The arrays can be nested up to three levels deep,
+/// [`ReshapeInto`] describes which combinations of arrays are supported.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `From` and `To`
+/// don't have the same amount of elements.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::reshape_ref;
+///
+/// const CUBE: &[[[u8; 2]; 2]; 2] = reshape_ref(&[3, 5, 8, 13, 21, 34, 55, 89]);
+///
+/// assert_eq!(CUBE, &[[[3, 5], [8, 13]], [[21, 34], [55, 89]]]);
+///
+/// ```
+#[inline(always)]
+pub const fn reshape_ref<From, To>(from: &From) -> &To
+where
+    From: ReshapeInto<To>,
+{
+    let _: () = Reshape::<From, To>::ASSERT_SAME_LEN;
+
+    // safety: `ReshapeInto<To>` guarantees that both `From` and `To` are
+    //         arrays of `Elem`s, with the same layout as `[Elem; LEN]`,
+    //         and the above assertion ensures that both `LEN`s are equal.
+    unsafe { __priv_transmute_ref!(From, To, from) }
+}
+
+/// Casts `&[[T; N]; M]` to `&[T; L]`,
+/// erroring at compile-time if `L != N * M`.
+///
+/// To cast `&[[T; N]; M]` to `&[T]`, you can use [`flatten_slice`].
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `L != N * M`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::flatten_ref;
+///
+/// const FLAT: &[u8; 6] = flatten_ref(&[[3, 5], [8, 13], [21, 34]]);
+///
+/// assert_eq!(FLAT, &[3, 5, 8, 13, 21, 34]);
+///
+/// ```
+#[inline(always)]
+pub const fn flatten_ref<T, const N: usize, const M: usize, const L: usize>(
+    from: &[[T; N]; M],
+) -> &[T; L] {
+    reshape_ref::<[[T; N]; M], [T; L]>(from)
+}
+
+/// Casts `&[[T; N]]` to `&[T]`
+///
+/// The length of the returned slice is `from.len() * N`.
+///
+/// # Panics
+///
+/// Panics if `from.len() * N` overflows,
+/// which can only happen if `T` is zero-sized.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::flatten_slice;
+///
+/// const FLAT: &[u16] = flatten_slice(&[[3, 5, 8], [13, 21, 34]]);
+///
+/// assert_eq!(FLAT, [3, 5, 8, 13, 21, 34]);
+///
+/// ```
+#[track_caller]
+pub const fn flatten_slice<T, const N: usize>(from: &[[T; N]]) -> &[T] {
+    let len = match from.len().checked_mul(N) {
+        Some(x) => x,
+        None => panic!("the length of the flattened slice overflows `usize`"),
+    };
+
+    // SAFETY: `[T; N]` has the same alignment as `T`,
+    //         and is laid out as `N` contiguous `T`s.
+    unsafe { core::slice::from_raw_parts(from.as_ptr().cast::<T>(), len) }
+}
+
+/// Casts `&[T]` to `&[[T; N]]`
+///
+/// The length of the returned slice is `from.len() / N`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_unflatten_slice`]
+/// returns [an error](crate::try_unflatten_slice#errors).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::unflatten_slice;
+///
+/// const PAIRS: &[[u16; 2]] = unflatten_slice(&[3, 5, 8, 13, 21, 34]);
+///
+/// assert_eq!(PAIRS, [[3, 5], [8, 13], [21, 34]]);
+///
+/// ```
+#[track_caller]
+pub const fn unflatten_slice<T, const N: usize>(from: &[T]) -> &[[T; N]] {
+    match try_unflatten_slice(from) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch) => panic!("cannot unflatten into zero-length arrays"),
+        Err(PodCastError::OutputSliceWouldHaveSlop | _) => unflatten_slop_panic(from.len(), N),
+    }
+}
+
+/// Tries to cast `&[T]` to `&[[T; N]]`
+///
+/// The length of the returned slice is `from.len() / N`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - `N` is `0` and `from` is not empty, returning a
+///   `Err(PodCastError::SizeMismatch)`.
+///
+/// - `from.len()` is not a multiple of `N`, returning a
+///   `Err(PodCastError::OutputSliceWouldHaveSlop)`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_unflatten_slice, PodCastError};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const TRIPLES: Res<&[[u8; 3]]> = try_unflatten_slice(&[3, 5, 8, 13, 21, 34]);
+/// assert_eq!(TRIPLES, Ok(&[[3, 5, 8], [13, 21, 34]][..]));
+///
+/// const ERR_SLOP: Res<&[[u8; 4]]> = try_unflatten_slice(&[3, 5, 8, 13, 21, 34]);
+/// assert_eq!(ERR_SLOP, Err(PodCastError::OutputSliceWouldHaveSlop));
+///
+/// ```
+pub const fn try_unflatten_slice<T, const N: usize>(from: &[T]) -> Result<&[[T; N]], PodCastError> {
+    if N == 0 {
+        if from.is_empty() {
+            Ok(&[])
+        } else {
+            Err(PodCastError::SizeMismatch)
+        }
+    } else if from.len() % N != 0 {
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    } else {
+        // SAFETY: `[T; N]` has the same alignment as `T`,
+        //         and `from.len()` is a multiple of `N`.
+        unsafe {
+            Ok(core::slice::from_raw_parts(
+                from.as_ptr().cast::<[T; N]>(),
+                from.len() / N,
+            ))
+        }
+    }
+}
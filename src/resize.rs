@@ -0,0 +1,256 @@
+use core::mem::{size_of, MaybeUninit};
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
+
+use crate::__priv_utils::Joined;
+
+/// Where the bytes of the input are placed in the output of
+/// [`cast_extend`] and [`cast_truncate`].
+///
+/// The position of the bytes is independent of the endianness of the target,
+/// so on little-endian targets, `Front` is the least significant bytes of an integer,
+/// while on big-endian targets, `Front` is the most significant bytes of an integer.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_extend, cast_truncate, Placement};
+///
+/// const FRONT: [u8; 4] = cast_extend([3u8, 5], Placement::Front);
+/// const BACK: [u8; 4] = cast_extend([3u8, 5], Placement::Back);
+///
+/// assert_eq!(FRONT, [3, 5, 0, 0]);
+/// assert_eq!(BACK, [0, 0, 3, 5]);
+///
+/// const FIRST: [u8; 2] = cast_truncate([3u8, 5, 8, 13], Placement::Front);
+/// const LAST: [u8; 2] = cast_truncate([3u8, 5, 8, 13], Placement::Back);
+///
+/// assert_eq!(FIRST, [3, 5]);
+/// assert_eq!(LAST, [8, 13]);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// The bytes of the input are at the start of the output.
+    Front,
+    /// The bytes of the input are at the end of the output.
+    Back,
+}
+
+/// Casts `T` into a `U` that is at least as large,
+/// filling the bytes of `U` that aren't from `T` with zeroes.
+///
+/// `placement` determines whether the bytes of `T` go at the
+/// start (`Placement::Front`) or end (`Placement::Back`) of `U`.
+///
+/// The [`AnyBitPattern`] bound of `U` implies [`Zeroable`](bytemuck::Zeroable),
+/// so zero bytes are always valid for `U`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_extend`]
+/// returns [an error](crate::try_cast_extend#errors),
+/// or [panics](crate::try_cast_extend#panics).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_extend, Placement};
+///
+/// const WIDE: u64 = cast_extend(0xABCD_u16.to_le(), Placement::Front);
+/// assert_eq!(u64::from_le(WIDE), 0xABCD);
+///
+/// const WIDE_BE: u64 = cast_extend(0xABCD_u16.to_be(), Placement::Back);
+/// assert_eq!(u64::from_be(WIDE_BE), 0xABCD);
+///
+/// ```
+#[track_caller]
+pub const fn cast_extend<T, U>(from: T, placement: Placement) -> U
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_extend(from, placement) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::extend_smaller_size_panic(size_of::<T>(), size_of::<U>())
+        }
+    }
+}
+
+/// Tries to cast `T` into a `U` that is at least as large,
+/// filling the bytes of `U` that aren't from `T` with zeroes.
+///
+/// `placement` determines whether the bytes of `T` go at the
+/// start (`Placement::Front`) or end (`Placement::Back`) of `U`.
+///
+/// # Errors
+///
+/// This function returns a `Err(PodCastError::SizeMismatch)` if
+/// `U` is smaller than `T`.
+///
+/// # Panics
+///
+/// If the `"rust_1_75"` feature is disabled,
+/// then this function panics when `size_of::<U>()` is larger than `1_048_576` bytes,
+/// like [`zeroed`](crate::zeroed) does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_extend, PodCastError, Placement};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const OK: Res<[u16; 3]> = try_cast_extend(3u16, Placement::Back);
+/// const ERR: Res<u16> = try_cast_extend(3u32, Placement::Back);
+///
+/// assert_eq!(OK, Ok([0, 0, 3]));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_extend<T, U>(from: T, placement: Placement) -> Result<U, PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    if size_of::<T>() > size_of::<U>() {
+        return Err(PodCastError::SizeMismatch);
+    }
+
+    let zeroed = crate::zeroed::<U>();
+
+    // The `T` is written over the start or end of the zeroed `U`,
+    // keeping the bytes of `zeroed` at the same offsets they have in the returned `U`.
+    match placement {
+        Placement::Front => {
+            let mut overlay = ResizeOverlay {
+                from_last: Joined(zeroed, from),
+            };
+            overlay.from_first.0 = from;
+
+            // SAFETY: `size_of::<T>() <= size_of::<U>()`,
+            //         so the `U` is `from` followed by the trailing bytes of `zeroed`,
+            //         and `U: AnyBitPattern` is valid for any bit pattern.
+            unsafe { Ok(overlay.from_last.0) }
+        }
+        Placement::Back => {
+            let mut overlay = ResizeOverlay {
+                from_first: Joined(from, zeroed),
+            };
+            overlay.from_last.1 = from;
+
+            // SAFETY: `size_of::<T>() <= size_of::<U>()`,
+            //         so the `U` is the leading bytes of `zeroed` followed by `from`,
+            //         and `U: AnyBitPattern` is valid for any bit pattern.
+            unsafe { Ok(overlay.from_first.1) }
+        }
+    }
+}
+
+// Overlays `T` followed by `U` with `U` followed by `T`,
+// so that the `U` in one field overlaps the start or end of the `T` in the other.
+#[repr(C)]
+union ResizeOverlay<T: Copy, U: Copy> {
+    from_first: Joined<T, U>,
+    from_last: Joined<U, T>,
+}
+
+/// Casts `T` into a `U` that is at most as large,
+/// keeping only the bytes of `T` that fit in `U`.
+///
+/// `placement` determines whether the bytes kept are from the
+/// start (`Placement::Front`) or end (`Placement::Back`) of `T`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_truncate`]
+/// returns [an error](crate::try_cast_truncate#errors).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_truncate, Placement};
+///
+/// const LOW: u16 = cast_truncate(0x1234_ABCD_u32.to_le(), Placement::Front);
+/// assert_eq!(u16::from_le(LOW), 0xABCD);
+///
+/// const LOW_BE: u16 = cast_truncate(0x1234_ABCD_u32.to_be(), Placement::Back);
+/// assert_eq!(u16::from_be(LOW_BE), 0xABCD);
+///
+/// ```
+#[track_caller]
+pub const fn cast_truncate<T, U>(from: T, placement: Placement) -> U
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_truncate(from, placement) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::truncate_larger_size_panic(size_of::<T>(), size_of::<U>())
+        }
+    }
+}
+
+/// Tries to cast `T` into a `U` that is at most as large,
+/// keeping only the bytes of `T` that fit in `U`.
+///
+/// `placement` determines whether the bytes kept are from the
+/// start (`Placement::Front`) or end (`Placement::Back`) of `T`.
+///
+/// # Errors
+///
+/// This function returns a `Err(PodCastError::SizeMismatch)` if
+/// `U` is larger than `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_truncate, PodCastError, Placement};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const OK: Res<[u16; 2]> = try_cast_truncate([3u16, 5, 8], Placement::Back);
+/// const ERR: Res<u64> = try_cast_truncate(3u32, Placement::Back);
+///
+/// assert_eq!(OK, Ok([5, 8]));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_truncate<T, U>(from: T, placement: Placement) -> Result<U, PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    if size_of::<T>() < size_of::<U>() {
+        return Err(PodCastError::SizeMismatch);
+    }
+
+    let uninit = MaybeUninit::<U>::uninit();
+
+    // The `U` is read from the start or end of `from`,
+    // without borrowing `from`, since that requires Rust 1.83.0.
+    match placement {
+        Placement::Front => {
+            let overlay = ResizeOverlay {
+                from_first: Joined(from, uninit),
+            };
+
+            // SAFETY: `size_of::<U>() <= size_of::<T>()`,
+            //         so the `U` is the leading bytes of `from`,
+            //         and `U: AnyBitPattern` is valid for any bit pattern.
+            unsafe { Ok(overlay.from_last.0.assume_init()) }
+        }
+        Placement::Back => {
+            let overlay = ResizeOverlay {
+                from_last: Joined(uninit, from),
+            };
+
+            // SAFETY: `size_of::<U>() <= size_of::<T>()`,
+            //         so the `U` is the trailing bytes of `from`,
+            //         and `U: AnyBitPattern` is valid for any bit pattern.
+            unsafe { Ok(overlay.from_first.1.assume_init()) }
+        }
+    }
+}
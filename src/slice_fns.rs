@@ -2,6 +2,8 @@ use core::mem::{align_of, size_of};
 
 use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
 
+use crate::__priv_utils::subslice;
+
 /// Casts `&T` to `&[u8]`
 ///
 /// # Example
@@ -37,6 +39,10 @@ where
 /// [`bytemuck::cast_slice`] allows `T` to have a lower alignment than `U`,
 /// so long as the `from` reference happens to be aligned to `U`.
 ///
+/// To cast a slice into a more aligned type,
+/// you can store the slice in an [`Aligned`](crate::Aligned),
+/// and use [`cast_slice_from_aligned`](crate::cast_slice_from_aligned).
+///
 /// # Example
 ///
 /// ```
@@ -55,23 +61,7 @@ where
 {
     match try_cast_slice_alt(from) {
         Ok(x) => x,
-        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => {
-            crate::__priv_utils::incompatible_alignment_panic(align_of::<T>(), align_of::<U>())
-        }
-        Err(PodCastError::OutputSliceWouldHaveSlop) => {
-            crate::__priv_utils::slice_does_not_divide_evenly_panic(
-                from.len(),
-                size_of::<T>(),
-                size_of::<U>(),
-            )
-        }
-        Err(PodCastError::SizeMismatch) => {
-            crate::__priv_utils::slice_cast_zst_panic(size_of::<T>(), size_of::<U>())
-        }
-        Err(PodCastError::AlignmentMismatch) => {
-            // can't use `unreachable` macro in const fn
-            panic!("unreachable!")
-        }
+        Err(e) => cast_slice_panic::<T, U>(from.len(), e),
     }
 }
 
@@ -100,6 +90,10 @@ where
 /// [`bytemuck::try_cast_slice`] allows `T` to have a lower alignment than `U`,
 /// so long as the `from` reference happens to be aligned to `U`.
 ///
+/// To cast a slice into a more aligned type,
+/// you can store the slice in an [`Aligned`](crate::Aligned),
+/// and use [`try_cast_slice_from_aligned`](crate::try_cast_slice_from_aligned).
+///
 /// # Example
 ///
 /// ```
@@ -138,28 +132,505 @@ where
     // `U: AnyBitPattern` guarantees that U is valid for any bit pattern.
     // both bounds combined mean that transmuting between them is safe,
     // provided that their size/alignment is compatible.
+    match cast_slice_len::<T, U>(from.len()) {
+        Ok(_) if size_of::<T>() == size_of::<U>() => {
+            // T is at least as aligned as U, and is the same size as U.
+            unsafe { Ok(__priv_transmute_slice! {T, U, from}) }
+        }
+        Ok(new_len) => {
+            // T is at least as aligned as U.
+            //
+            // (size_of::<T>() * from.len()) divides evenly into size_of::<U>()
+            unsafe {
+                Ok(core::slice::from_raw_parts(
+                    from.as_ptr().cast::<U>(),
+                    new_len,
+                ))
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Casts the longest castable prefix of `&[T]` to `&[U]`,
+/// returning it along with the remaining `&[T]` elements.
+///
+/// The returned `&[U]` covers as many elements of `from` as possible,
+/// such that their size in bytes divides evenly into `size_of::<U>()`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_slice_prefix_alt`]
+/// returns [an error](crate::try_cast_slice_prefix_alt#errors).
+///
+/// # Example
+///
+/// ```
+/// use constmuck::cast_slice_prefix_alt;
+///
+/// const SPLIT: (&[[u8; 3]], &[u8]) = cast_slice_prefix_alt(&[3u8, 5, 8, 13, 21, 34, 55, 89]);
+///
+/// assert_eq!(SPLIT.0, [[3, 5, 8], [13, 21, 34]]);
+/// assert_eq!(SPLIT.1, [55, 89]);
+///
+/// ```
+#[track_caller]
+pub const fn cast_slice_prefix_alt<T, U>(from: &[T]) -> (&[U], &[T])
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_slice_prefix_alt(from) {
+        Ok(x) => x,
+        Err(e) => cast_slice_panic::<T, U>(from.len(), e),
+    }
+}
+
+/// Tries to cast the longest castable prefix of `&[T]` to `&[U]`,
+/// returning it along with the remaining `&[T]` elements.
+///
+/// The returned `&[U]` covers as many elements of `from` as possible,
+/// such that their size in bytes divides evenly into `size_of::<U>()`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The alignment of `T` is larger than `U`, returning a
+///   `Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)`.
+///
+/// - `T` xor `U` is zero-sized, but the other type parameter isn't zero-sized,
+///   returning a `Err(PodCastError::SizeMismatch)`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::PodCastError;
+/// use constmuck::try_cast_slice_prefix_alt;
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const SPLIT: Res<(&[[u16; 3]], &[[u16; 2]])> =
+///     try_cast_slice_prefix_alt(&[[3u16, 5], [8, 13], [21, 34], [55, 89]]);
+/// assert_eq!(SPLIT, Ok((&[[3, 5, 8], [13, 21, 34]][..], &[[55, 89]][..])));
+///
+/// const ERR_ALIGN: Res<(&[u16], &[u8])> = try_cast_slice_prefix_alt(&[3u8, 5, 8]);
+/// assert_eq!(ERR_ALIGN, Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned));
+///
+/// ```
+pub const fn try_cast_slice_prefix_alt<T, U>(from: &[T]) -> Result<(&[U], &[T]), PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match cast_slice_prefix_len::<T, U>(from.len()) {
+        // SAFETY:
+        // the bounds guarantee that transmuting from `T` to `U` is safe,
+        // provided that their size/alignment is compatible,
+        // which `cast_slice_prefix_len` checks for the first `t_len` elements.
+        Ok((t_len, u_len)) => unsafe {
+            let rem = subslice(from, t_len, from.len());
+            let prefix = core::slice::from_raw_parts(from.as_ptr().cast::<U>(), u_len);
+            Ok((prefix, rem))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Casts the longest castable suffix of `&[T]` to `&[U]`,
+/// returning it along with the preceding `&[T]` elements.
+///
+/// The returned `&[U]` covers as many elements of `from` as possible,
+/// such that their size in bytes divides evenly into `size_of::<U>()`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_slice_suffix_alt`]
+/// returns [an error](crate::try_cast_slice_suffix_alt#errors).
+///
+/// # Example
+///
+/// ```
+/// use constmuck::cast_slice_suffix_alt;
+///
+/// const SPLIT: (&[u8], &[[u8; 3]]) = cast_slice_suffix_alt(&[3u8, 5, 8, 13, 21, 34, 55, 89]);
+///
+/// assert_eq!(SPLIT.0, [3, 5]);
+/// assert_eq!(SPLIT.1, [[8, 13, 21], [34, 55, 89]]);
+///
+/// ```
+#[track_caller]
+pub const fn cast_slice_suffix_alt<T, U>(from: &[T]) -> (&[T], &[U])
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_slice_suffix_alt(from) {
+        Ok(x) => x,
+        Err(e) => cast_slice_panic::<T, U>(from.len(), e),
+    }
+}
+
+/// Tries to cast the longest castable suffix of `&[T]` to `&[U]`,
+/// returning it along with the preceding `&[T]` elements.
+///
+/// The returned `&[U]` covers as many elements of `from` as possible,
+/// such that their size in bytes divides evenly into `size_of::<U>()`.
+///
+/// # Errors
+///
+/// This function returns errors in the same cases as
+/// [`try_cast_slice_prefix_alt`](crate::try_cast_slice_prefix_alt#errors).
+///
+/// # Example
+///
+/// ```
+/// use constmuck::PodCastError;
+/// use constmuck::try_cast_slice_suffix_alt;
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const SPLIT: Res<(&[u32], &[[u32; 2]])> = try_cast_slice_suffix_alt(&[3u32, 5, 8, 13, 21]);
+/// assert_eq!(SPLIT, Ok((&[3][..], &[[5, 8], [13, 21]][..])));
+///
+/// const ERR_ZST: Res<(&[u8], &[()])> = try_cast_slice_suffix_alt(&[3u8]);
+/// assert_eq!(ERR_ZST, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_slice_suffix_alt<T, U>(from: &[T]) -> Result<(&[T], &[U]), PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match cast_slice_prefix_len::<T, U>(from.len()) {
+        // SAFETY:
+        // the bounds guarantee that transmuting from `T` to `U` is safe,
+        // provided that their size/alignment is compatible,
+        // which `cast_slice_prefix_len` checks for the last `t_len` elements.
+        Ok((t_len, u_len)) => unsafe {
+            let rem = subslice(from, 0, from.len() - t_len);
+            let suffix_start = from.as_ptr().add(rem.len()).cast::<U>();
+            Ok((rem, core::slice::from_raw_parts(suffix_start, u_len)))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Splits `&[T]` into a slice of `N`-element arrays,
+/// and a remainder slice with fewer than `N` elements.
+///
+/// # Panics
+///
+/// Panics if `N` is `0`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::as_array_chunks;
+///
+/// const RGB: (&[[u8; 3]], &[u8]) = as_array_chunks(&[255, 0, 0, 0, 255, 0, 0, 0]);
+///
+/// assert_eq!(RGB.0, [[255, 0, 0], [0, 255, 0]]);
+/// assert_eq!(RGB.1, [0, 0]);
+///
+/// ```
+#[track_caller]
+pub const fn as_array_chunks<T, const N: usize>(from: &[T]) -> (&[[T; N]], &[T]) {
+    let chunks_len = array_chunks_len::<N>(from.len());
+    let prefix = subslice(from, 0, chunks_len * N);
+
+    // SAFETY: `[T; N]` has the same alignment as `T`,
+    //         and `prefix` is `chunks_len * N` elements long.
+    let chunks = unsafe { __priv_transmute_slice!(T, [T; N], prefix, len = chunks_len) };
+
+    (chunks, subslice(from, prefix.len(), from.len()))
+}
+
+/// Splits `&[T]` into a remainder slice with fewer than `N` elements,
+/// and a slice of `N`-element arrays.
+///
+/// # Panics
+///
+/// Panics if `N` is `0`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::as_rarray_chunks;
+///
+/// const ROWS: (&[u16], &[[u16; 2]]) = as_rarray_chunks(&[3, 5, 8, 13, 21]);
+///
+/// assert_eq!(ROWS.0, [3]);
+/// assert_eq!(ROWS.1, [[5, 8], [13, 21]]);
+///
+/// ```
+#[track_caller]
+pub const fn as_rarray_chunks<T, const N: usize>(from: &[T]) -> (&[T], &[[T; N]]) {
+    let chunks_len = array_chunks_len::<N>(from.len());
+    let rem = subslice(from, 0, from.len() - chunks_len * N);
+    let suffix = subslice(from, rem.len(), from.len());
+
+    // SAFETY: `[T; N]` has the same alignment as `T`,
+    //         and `suffix` is `chunks_len * N` elements long.
+    let chunks = unsafe { __priv_transmute_slice!(T, [T; N], suffix, len = chunks_len) };
+
+    (rem, chunks)
+}
+
+/// Casts `&[T]` to `&[T; N]` if the slice is exactly `N` elements long,
+/// otherwise returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::as_array;
+///
+/// const SOME: Option<&[u8; 3]> = as_array(&[3, 5, 8]);
+/// const NONE: Option<&[u8; 2]> = as_array(&[3, 5, 8]);
+///
+/// assert_eq!(SOME, Some(&[3, 5, 8]));
+/// assert_eq!(NONE, None);
+///
+/// ```
+pub const fn as_array<T, const N: usize>(from: &[T]) -> Option<&[T; N]> {
+    if from.len() == N {
+        // SAFETY: `[T; N]` has the same alignment as `T`,
+        //         and `from` is exactly `N` elements long.
+        unsafe { Some(__priv_transmute_ref!([T], [T; N], from)) }
+    } else {
+        None
+    }
+}
+
+/// Casts `&mut T` to `&mut [u8]`
+///
+/// Requires the `"rust_1_83"` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::bytes_of_mut;
+///
+/// const NUM: u32 = {
+///     let mut num = 0u32;
+///     let bytes = bytes_of_mut(&mut num);
+///     bytes[0] = 0xFF;
+///     bytes[1] = 0xFF;
+///     num
+/// };
+///
+/// assert_eq!(NUM, u32::from_ne_bytes([0xFF, 0xFF, 0, 0]));
+/// ```
+#[cfg(feature = "rust_1_83")]
+pub const fn bytes_of_mut<T>(bytes: &mut T) -> &mut [u8]
+where
+    T: NoUninit + AnyBitPattern,
+{
+    // safety: `T: NoUninit` guarantees that T doesn't have any padding or uninit bytes,
+    //         `T: AnyBitPattern` guarantees that any bytes written are a valid `T`.
+    #[allow(clippy::incompatible_msrv)] // requires the "rust_1_83" feature
+    unsafe {
+        core::slice::from_raw_parts_mut(bytes as *mut T as *mut u8, size_of::<T>())
+    }
+}
+
+/// Casts `&mut [T]` to `&mut [U]`
+///
+/// Requires the `"rust_1_83"` feature.
+///
+/// If this function does not panic,
+/// the length of the returned slice is `from.len() * size_of::<T>() / size_of::<U>()`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_slice_mut_alt`]
+/// returns [an error](crate::try_cast_slice_mut_alt#errors).
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::cast_slice_mut`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::cast_slice_mut_alt;
+///
+/// const ARR: [u8; 6] = {
+///     let mut arr = [0u8; 6];
+///     let pairs: &mut [[u8; 2]] = cast_slice_mut_alt(&mut arr);
+///     pairs[1] = [3, 5];
+///     pairs[2][0] = 8;
+///     arr
+/// };
+///
+/// assert_eq!(ARR, [0, 0, 3, 5, 8, 0]);
+///
+/// ```
+#[cfg(feature = "rust_1_83")]
+#[track_caller]
+pub const fn cast_slice_mut_alt<T, U>(from: &mut [T]) -> &mut [U]
+where
+    T: NoUninit + AnyBitPattern,
+    U: NoUninit + AnyBitPattern,
+{
+    let len = from.len();
+    match try_cast_slice_mut_alt(from) {
+        Ok(x) => x,
+        Err(e) => cast_slice_panic::<T, U>(len, e),
+    }
+}
+
+/// Tries to cast `&mut [T]` to `&mut [U]`
+///
+/// Requires the `"rust_1_83"` feature.
+///
+/// If this function returns successfully,
+/// the length of the returned slice is `from.len() * size_of::<T>() / size_of::<U>()`.
+///
+/// # Errors
+///
+/// This function returns errors in the same cases as
+/// [`try_cast_slice_alt`](crate::try_cast_slice_alt#errors).
+///
+/// # Difference with `bytemuck`
+///
+/// This function requires `T` to have an alignment larger than or equal to `U`.
+/// [`bytemuck::try_cast_slice_mut`] allows `T` to have a lower alignment than `U`,
+/// so long as the `from` reference happens to be aligned to `U`.
+///
+/// # Example
+///
+/// ```
+/// use constmuck::PodCastError;
+/// use constmuck::try_cast_slice_mut_alt;
+///
+/// const RES: ([u16; 2], Result<(), PodCastError>) = {
+///     let mut arr = [0u16; 2];
+///
+///     let err = match try_cast_slice_mut_alt::<u16, [u8; 3]>(&mut arr) {
+///         Ok(_) => Ok(()),
+///         Err(e) => Err(e),
+///     };
+///
+///     if let Ok(x) = try_cast_slice_mut_alt::<u16, i16>(&mut arr) {
+///         x[1] = -1;
+///     }
+///
+///     (arr, err)
+/// };
+///
+/// assert_eq!(RES, ([0, u16::MAX], Err(PodCastError::OutputSliceWouldHaveSlop)));
+///
+/// ```
+#[cfg(feature = "rust_1_83")]
+pub const fn try_cast_slice_mut_alt<T, U>(from: &mut [T]) -> Result<&mut [U], PodCastError>
+where
+    T: NoUninit + AnyBitPattern,
+    U: NoUninit + AnyBitPattern,
+{
+    match cast_slice_len::<T, U>(from.len()) {
+        // SAFETY:
+        // the bounds guarantee that both `T` and `U` contain no uninitialized bytes,
+        // and are valid for any bit pattern,
+        // so writes through the returned slice can't produce invalid `T`s.
+        // `cast_slice_len` checks that their size/alignment is compatible.
+        #[allow(clippy::incompatible_msrv)] // requires the "rust_1_83" feature
+        Ok(new_len) => unsafe {
+            Ok(core::slice::from_raw_parts_mut(
+                from.as_mut_ptr().cast::<U>(),
+                new_len,
+            ))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+// Casts `&[T]` to `&[u8]`, unlike `cast_slice_alt`, this supports zero-sized `T`
+pub(crate) const fn slice_bytes<T: NoUninit>(slice: &[T]) -> &[u8] {
+    // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+    //         and the byte slice covers the same memory as `slice`.
+    unsafe {
+        core::slice::from_raw_parts(slice.as_ptr().cast::<u8>(), slice.len() * size_of::<T>())
+    }
+}
+
+// Computes the length of the `[U]` that a `[T]` of length `len` is cast into,
+// returning an error if the cast can't be done.
+pub(crate) const fn cast_slice_len<T, U>(len: usize) -> Result<usize, PodCastError> {
     if align_of::<T>() < align_of::<U>() {
         Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
     } else if size_of::<T>() == size_of::<U>() {
-        // T is at least as aligned as U, and is the same size as U.
-        unsafe { Ok(__priv_transmute_slice! {T, U, from}) }
+        Ok(len)
     } else if size_of::<T>() == 0 || size_of::<U>() == 0 {
         Err(PodCastError::SizeMismatch)
-    } else if let Some(new_len) = exact_div(from.len() * size_of::<T>(), size_of::<U>()) {
-        // T is at least as aligned as U.
-        //
-        // (size_of::<T>() * from.len()) divides evenly into size_of::<U>()
-        unsafe {
-            Ok(core::slice::from_raw_parts(
-                from.as_ptr().cast::<U>(),
-                new_len,
-            ))
-        }
+    } else if let Some(new_len) = exact_div(len * size_of::<T>(), size_of::<U>()) {
+        Ok(new_len)
     } else {
         Err(PodCastError::OutputSliceWouldHaveSlop)
     }
 }
 
+// Computes how many elements of a `[T]` of length `len` can be cast to a `[U]`,
+// returning that amount along with the length of the `[U]`.
+const fn cast_slice_prefix_len<T, U>(len: usize) -> Result<(usize, usize), PodCastError> {
+    if align_of::<T>() < align_of::<U>() {
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    } else if size_of::<T>() == size_of::<U>() {
+        Ok((len, len))
+    } else if size_of::<T>() == 0 || size_of::<U>() == 0 {
+        Err(PodCastError::SizeMismatch)
+    } else {
+        // the amount of `T`s in the smallest group whose size is a multiple of `U`'s size
+        let group_len = size_of::<U>() / gcd(size_of::<T>(), size_of::<U>());
+        let t_len = len - len % group_len;
+        Ok((t_len, t_len * size_of::<T>() / size_of::<U>()))
+    }
+}
+
+// Panics with the message for an error returned by `cast_slice_len`
+#[track_caller]
+pub(crate) const fn cast_slice_panic<T, U>(len: usize, err: PodCastError) -> ! {
+    match err {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned => {
+            crate::__priv_utils::incompatible_alignment_panic(align_of::<T>(), align_of::<U>())
+        }
+        PodCastError::OutputSliceWouldHaveSlop => {
+            crate::__priv_utils::slice_does_not_divide_evenly_panic(
+                len,
+                size_of::<T>(),
+                size_of::<U>(),
+            )
+        }
+        PodCastError::SizeMismatch => {
+            crate::__priv_utils::slice_cast_zst_panic(size_of::<T>(), size_of::<U>())
+        }
+        PodCastError::AlignmentMismatch => {
+            // can't use `unreachable` macro in const fn
+            panic!("unreachable!")
+        }
+    }
+}
+
+// Computes how many `N`-element arrays fit in a slice of length `len`
+#[track_caller]
+const fn array_chunks_len<const N: usize>(len: usize) -> usize {
+    if N == 0 {
+        panic!("chunk size must be non-zero")
+    }
+    len / N
+}
+
+// Computes the greatest common divisor of `a` and `b`
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
 // Returns dividend / divisor iff the division has no remainder,
 // otherwise returns None.
 //
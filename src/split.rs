@@ -0,0 +1,199 @@
+use core::{marker::PhantomData, mem::size_of};
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
+
+use crate::{
+    __priv_utils::{subslice, Joined},
+    pod::read_unaligned_unchecked,
+    slice_fns::bytes_of,
+};
+
+// Associated constant that causes a compile-time error
+// when `T` isn't as large as `A` and `B` combined.
+struct Split<T, A, B>(PhantomData<(T, A, B)>);
+
+impl<T, A, B> Split<T, A, B> {
+    const ASSERT_SIZE_SUM: () = if size_of::<T>() != size_of::<A>() + size_of::<B>() {
+        crate::__priv_utils::split_unequal_size_panic(
+            size_of::<T>(),
+            size_of::<A>(),
+            size_of::<B>(),
+        )
+    };
+}
+
+/// Splits `T` into an `A` made from its first bytes, and a `B` made from the rest,
+/// erroring at compile-time if the sizes don't add up.
+///
+/// This is the inverse of [`join_pod`].
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if
+/// `size_of::<A>() + size_of::<B>()` is not equal to `size_of::<T>()`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::split_pod;
+///
+/// const PARTS: (u64, [u8; 8]) = split_pod([0xAB_u8; 16]);
+///
+/// assert_eq!(PARTS, (0xABAB_ABAB_ABAB_ABAB, [0xAB; 8]));
+///
+/// ```
+///
+/// Splitting into parts that don't add up to the size of `T` is an error:
+///
+/// ```compile_fail
+/// const PARTS: (u32, u32) = constmuck::split_pod(0u32);
+/// ```
+#[inline(always)]
+pub const fn split_pod<T, A, B>(from: T) -> (A, B)
+where
+    T: NoUninit,
+    A: AnyBitPattern,
+    B: AnyBitPattern,
+{
+    let _: () = Split::<T, A, B>::ASSERT_SIZE_SUM;
+
+    // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+    //         `A: AnyBitPattern` and `B: AnyBitPattern` guarantee that
+    //         they're valid for any bit pattern.
+    //         `Joined<A, B>` has no padding, so it's as large as `A` and `B` combined,
+    //         which the above assertion checks to be the size of `T`.
+    let joined = unsafe { __priv_transmute_from_copy!(T, Joined<A, B>, from) };
+
+    (joined.0, joined.1)
+}
+
+/// Joins `A` and `B` into a `T`, placing the bytes of `A` before the bytes of `B`,
+/// erroring at compile-time if the sizes don't add up.
+///
+/// This is the inverse of [`split_pod`].
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if
+/// `size_of::<A>() + size_of::<B>()` is not equal to `size_of::<T>()`.
+///
+/// Because the check happens after monomorphization,
+/// `cargo check` might not report the error, but `cargo build` does.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::join_pod;
+///
+/// const JOINED: [u16; 3] = join_pod(3u16, [5u16, 8]);
+///
+/// assert_eq!(JOINED, [3, 5, 8]);
+///
+/// ```
+///
+/// Joining parts that don't add up to the size of `T` is an error:
+///
+/// ```compile_fail
+/// const JOINED: u32 = constmuck::join_pod(0u16, 0u8);
+/// ```
+#[inline(always)]
+pub const fn join_pod<A, B, T>(a: A, b: B) -> T
+where
+    A: NoUninit,
+    B: NoUninit,
+    T: AnyBitPattern,
+{
+    let _: () = Split::<T, A, B>::ASSERT_SIZE_SUM;
+
+    // SAFETY: `A: NoUninit` and `B: NoUninit` guarantee that
+    //         they contain no uninitialized bytes,
+    //         and `Joined<A, B>` has no padding between or after them.
+    //         `T: AnyBitPattern` guarantees that `T` is valid for any bit pattern,
+    //         and the above assertion checks that `T` is as large as `Joined<A, B>`.
+    unsafe { __priv_transmute_from_copy!(Joined<A, B>, T, Joined(a, b)) }
+}
+
+/// Reads an `A` from the bytes of `from` starting at `offset`,
+/// returning it along with the bytes of `from` that come after it.
+///
+/// # Panics
+///
+/// This function panics if `offset + size_of::<A>()` is larger than `size_of::<T>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::split_pod_at;
+///
+/// const HEADER: [u8; 8] = *b"\x02ab\x05cdef";
+///
+/// const FIRST: (u8, &[u8]) = split_pod_at(&HEADER, 0);
+/// const SECOND: ([u8; 2], &[u8]) = split_pod_at(&HEADER, 1);
+/// const LAST: ([u8; 4], &[u8]) = split_pod_at(&HEADER, 4);
+///
+/// assert_eq!(FIRST, (2, &b"ab\x05cdef"[..]));
+/// assert_eq!(SECOND, (*b"ab", &b"\x05cdef"[..]));
+/// assert_eq!(LAST, (*b"cdef", &[][..]));
+///
+/// ```
+#[track_caller]
+pub const fn split_pod_at<T, A>(from: &T, offset: usize) -> (A, &[u8])
+where
+    T: NoUninit,
+    A: AnyBitPattern,
+{
+    match try_split_pod_at(from, offset) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::split_out_of_bounds_panic(offset, size_of::<A>(), size_of::<T>())
+        }
+    }
+}
+
+/// Tries to read an `A` from the bytes of `from` starting at `offset`,
+/// returning it along with the bytes of `from` that come after it.
+///
+/// # Errors
+///
+/// This function returns a `Err(PodCastError::SizeMismatch)`
+/// if `offset + size_of::<A>()` is larger than `size_of::<T>()`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_split_pod_at, PodCastError};
+///
+/// type Res<'a, T> = Result<(T, &'a [u8]), PodCastError>;
+///
+/// const OK: Res<'_, u16> = try_split_pod_at(&[3u16, 5, 8], 2);
+/// const ERR: Res<'_, u32> = try_split_pod_at(&[3u16, 5, 8], 4);
+///
+/// let (num, rest) = OK.unwrap();
+/// assert_eq!(num, 5);
+/// assert_eq!(rest, 8u16.to_ne_bytes());
+///
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_split_pod_at<T, A>(from: &T, offset: usize) -> Result<(A, &[u8]), PodCastError>
+where
+    T: NoUninit,
+    A: AnyBitPattern,
+{
+    let bytes = bytes_of(from);
+
+    if offset <= bytes.len() && size_of::<A>() <= bytes.len() - offset {
+        let end = offset + size_of::<A>();
+
+        // SAFETY: the slice is as large as `A`,
+        //         and `A: AnyBitPattern` is valid for any bit pattern.
+        let value = unsafe { read_unaligned_unchecked(subslice(bytes, offset, end)) };
+
+        Ok((value, subslice(bytes, end, bytes.len())))
+    } else {
+        Err(PodCastError::SizeMismatch)
+    }
+}
@@ -0,0 +1,128 @@
+/// Gets the bytes of a [`NoUninit`](crate::NoUninit) constant as a `&'static [u8]`.
+///
+/// The argument must be usable in a `const` item,
+/// it's converted into a byte array that's promoted to `'static`,
+/// and the bytes are the same as those returned by [`bytes_of`](crate::bytes_of).
+///
+/// Unlike `bytes_of(&CONSTANT)`,
+/// which borrows a temporary when used inside a larger const expression,
+/// this macro can be used anywhere in the initializer of a `const` or `static`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{static_bytes_of, NoUninit};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, NoUninit)]
+/// struct Header {
+///     magic: [u8; 4],
+///     len: u32,
+/// }
+///
+/// struct Blob {
+///     name: &'static str,
+///     bytes: &'static [u8],
+/// }
+///
+/// const HEADER: Header = Header { magic: *b"ROM!", len: 8u32.to_be() };
+/// const TABLE: [u16; 2] = [3u16.to_be(), 5u16.to_be()];
+///
+/// static BLOBS: [Blob; 2] = [
+///     Blob { name: "header", bytes: static_bytes_of!(HEADER) },
+///     Blob { name: "table", bytes: static_bytes_of!(TABLE) },
+/// ];
+///
+/// assert_eq!(BLOBS[0].name, "header");
+/// assert_eq!(BLOBS[0].bytes, b"ROM!\0\0\0\x08");
+///
+/// assert_eq!(BLOBS[1].name, "table");
+/// assert_eq!(BLOBS[1].bytes, [0, 3, 0, 5]);
+///
+/// ```
+#[macro_export]
+macro_rules! static_bytes_of {
+    ($value:expr $(,)?) => {{
+        const __LEN: usize = $crate::__::size_of_val_pod(&$value);
+
+        const __OUT: &'static [u8] = &$crate::to_byte_array::<_, __LEN>($value);
+
+        __OUT
+    }};
+}
+
+/// Casts a [`NoUninit`](crate::NoUninit) constant into a `&'static U`.
+///
+/// The argument must be usable in a `const` item,
+/// it's cast with [`cast`](crate::cast) into a `U` that's promoted to `'static`.
+/// Since the casted value is stored by value,
+/// `U` can be more aligned than the type of the argument.
+///
+/// Unlike `cast_ref_alt(&CONSTANT)`,
+/// which borrows a temporary when used inside a larger const expression,
+/// this macro can be used anywhere in the initializer of a `const` or `static`.
+///
+/// # Syntax
+///
+/// This macro takes the value, followed by `as` and the type to cast into:
+///
+/// ```text
+/// static_cast_ref!(VALUE as Type)
+/// ```
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the argument is not the same size as `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::static_cast_ref;
+///
+/// struct Entry {
+///     id: &'static [u8; 4],
+///     words: &'static [u32; 2],
+/// }
+///
+/// const ID: u32 = 0x2A00_00FF;
+/// const BYTES: [u8; 8] = *b"\x03\0\0\0\x05\0\0\0";
+///
+/// const ENTRY: Entry = Entry {
+///     id: static_cast_ref!(ID as [u8; 4]),
+///     words: static_cast_ref!(BYTES as [u32; 2]),
+/// };
+///
+/// assert_eq!(ENTRY.id, &0x2A00_00FFu32.to_ne_bytes());
+/// assert_eq!(ENTRY.words, &[3u32.to_le(), 5u32.to_le()]);
+///
+/// ```
+///
+/// Casting between types of different sizes is an error:
+///
+/// ```compile_fail
+/// const NUM: &u32 = constmuck::static_cast_ref!(0u16 as u32);
+/// ```
+#[macro_export]
+macro_rules! static_cast_ref {
+    ($($tokens:tt)*) => {
+        $crate::__static_cast_ref_inner!(() $($tokens)*)
+    };
+}
+
+// Finds the `as` that separates the value from the type,
+// since an `expr` fragment can't be followed by `as`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __static_cast_ref_inner {
+    (($($value:tt)+) as $ty:ty $(,)?) => {{
+        const __OUT: &'static $ty = &$crate::cast::<_, $ty>($($value)+);
+
+        __OUT
+    }};
+    (($($value:tt)*) $first:tt $($rest:tt)*) => {
+        $crate::__static_cast_ref_inner!(($($value)* $first) $($rest)*)
+    };
+    (($($value:tt)*)) => {
+        $crate::__::compile_error!("expected arguments of the form `VALUE as Type`")
+    };
+}
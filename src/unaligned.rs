@@ -0,0 +1,295 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    mem::size_of,
+};
+
+use bytemuck::{AnyBitPattern, NoUninit, Pod, PodCastError, Zeroable};
+
+use crate::slice_fns::{cast_slice_len, cast_slice_panic};
+
+/// A `T` with an alignment of `1`.
+///
+/// This allows reading a `T` from bytes that aren't aligned for `T`,
+/// returned by [`cast_ref_unaligned`] and [`cast_slice_unaligned`].
+///
+/// Because the `T` might not be aligned, it can only be read by value,
+/// with the [`get`](Self::get) method.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_slice_unaligned, Unaligned};
+///
+/// // `u8` is less aligned than `u32`, so this is casted into `Unaligned<u32>`
+/// const WORDS: &[Unaligned<u32>] = cast_slice_unaligned(&[1u8, 0, 0, 0, 2, 0, 0, 0]);
+///
+/// assert_eq!(WORDS.len(), 2);
+/// assert_eq!(WORDS[0].get(), u32::from_ne_bytes([1, 0, 0, 0]));
+/// assert_eq!(WORDS[1].get(), u32::from_ne_bytes([2, 0, 0, 0]));
+///
+/// assert_eq!(std::mem::align_of::<Unaligned<u32>>(), 1);
+///
+/// ```
+#[repr(C, packed)]
+pub struct Unaligned<T>(T);
+
+impl<T> Unaligned<T> {
+    /// Constructs an `Unaligned` from the value it wraps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::Unaligned;
+    ///
+    /// const PAIR: [Unaligned<u64>; 2] = [Unaligned::new(3), Unaligned::new(5)];
+    ///
+    /// assert_eq!(PAIR[0].get() + PAIR[1].get(), 8);
+    ///
+    /// ```
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Copy> Unaligned<T> {
+    /// Gets the value that this wraps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{cast_ref_unaligned, Unaligned};
+    ///
+    /// const BYTES: [u8; 4] = 0xBEEF_F00Du32.to_ne_bytes();
+    ///
+    /// const NUM: u32 = cast_ref_unaligned::<_, u32>(&BYTES).get();
+    ///
+    /// assert_eq!(NUM, 0xBEEF_F00D);
+    ///
+    /// ```
+    #[inline(always)]
+    pub const fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Copy> Copy for Unaligned<T> {}
+
+impl<T: Copy> Clone for Unaligned<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy + Debug> Debug for Unaligned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Unaligned").field(&self.get()).finish()
+    }
+}
+
+impl<T: Copy + PartialEq> PartialEq for Unaligned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Copy + Eq> Eq for Unaligned<T> {}
+
+impl<T: Copy + PartialOrd> PartialOrd for Unaligned<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.get().partial_cmp(&other.get())
+    }
+}
+
+impl<T: Copy + Ord> Ord for Unaligned<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl<T: Copy + Hash> Hash for Unaligned<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state)
+    }
+}
+
+impl<T: Default> Default for Unaligned<T> {
+    fn default() -> Self {
+        Self(T::default())
+    }
+}
+
+impl<T> From<T> for Unaligned<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+// SAFETY: `Unaligned<T>` only contains a `T`, which is zeroable
+unsafe impl<T: Zeroable> Zeroable for Unaligned<T> {}
+
+// SAFETY: `Unaligned<T>` only contains a `T`, which is `Pod`,
+//         and `#[repr(C, packed)]` doesn't add padding.
+unsafe impl<T: Pod> Pod for Unaligned<T> {}
+
+/// Casts `&T` to `&Unaligned<U>`
+///
+/// Unlike [`cast_ref_alt`](crate::cast_ref_alt),
+/// this function allows `U` to have a larger alignment than `T`.
+///
+/// # Panics
+///
+/// This function panics if the size of `T` is not equal to `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_ref_unaligned, Unaligned};
+///
+/// const NUM: &Unaligned<u64> = cast_ref_unaligned(&[0x12345678u32, 0x9ABCDEF0]);
+///
+/// assert_eq!(
+///     NUM.get(),
+///     constmuck::cast::<[u32; 2], u64>([0x12345678, 0x9ABCDEF0]),
+/// );
+///
+/// ```
+#[track_caller]
+pub const fn cast_ref_unaligned<T, U>(from: &T) -> &Unaligned<U>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_ref_unaligned(from) {
+        Ok(x) => x,
+        Err(PodCastError::SizeMismatch | _) => {
+            crate::__priv_utils::unequal_size_panic(size_of::<T>(), size_of::<U>())
+        }
+    }
+}
+
+/// Tries to cast `&T` to `&Unaligned<U>`
+///
+/// Unlike [`try_cast_ref_alt`](crate::try_cast_ref_alt),
+/// this function allows `U` to have a larger alignment than `T`.
+///
+/// # Errors
+///
+/// This function returns an `Err(PodCastError::SizeMismatch)`
+/// if the size of `T` is not equal to `U`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_ref_unaligned, PodCastError, Unaligned};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const OK: Res<&Unaligned<u16>> = try_cast_ref_unaligned(&[3u8, 5]);
+/// const ERR: Res<&Unaligned<u16>> = try_cast_ref_unaligned(&[3u8, 5, 8]);
+///
+/// assert_eq!(OK.unwrap().get(), u16::from_ne_bytes([3, 5]));
+/// assert_eq!(ERR, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_ref_unaligned<T, U>(from: &T) -> Result<&Unaligned<U>, PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    if size_of::<T>() == size_of::<U>() {
+        // SAFETY: `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        //         `U: AnyBitPattern` guarantees that U is valid for any bit pattern.
+        //         `Unaligned<U>` has an alignment of 1,
+        //         and is the same size as `T` in this branch.
+        unsafe { Ok(&*(from as *const T as *const Unaligned<U>)) }
+    } else {
+        Err(PodCastError::SizeMismatch)
+    }
+}
+
+/// Casts `&[T]` to `&[Unaligned<U>]`
+///
+/// Unlike [`cast_slice_alt`](crate::cast_slice_alt),
+/// this function allows `U` to have a larger alignment than `T`.
+///
+/// # Panics
+///
+/// This function panics in the cases where [`try_cast_slice_unaligned`]
+/// returns [an error](crate::try_cast_slice_unaligned#errors).
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{cast_slice_unaligned, Unaligned};
+///
+/// const HALVES: &[Unaligned<u16>] = cast_slice_unaligned(&[[3u8, 5, 8, 13]; 2]);
+///
+/// assert_eq!(HALVES.len(), 4);
+/// assert_eq!(HALVES[1].get(), u16::from_ne_bytes([8, 13]));
+///
+/// ```
+#[track_caller]
+pub const fn cast_slice_unaligned<T, U>(from: &[T]) -> &[Unaligned<U>]
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match try_cast_slice_unaligned(from) {
+        Ok(x) => x,
+        Err(e) => cast_slice_panic::<T, Unaligned<U>>(from.len(), e),
+    }
+}
+
+/// Tries to cast `&[T]` to `&[Unaligned<U>]`
+///
+/// Unlike [`try_cast_slice_alt`](crate::try_cast_slice_alt),
+/// this function allows `U` to have a larger alignment than `T`.
+///
+/// # Errors
+///
+/// This function returns errors in these cases:
+/// - The size of `T` is not equal to `U`, and either type is zero-sized, returning a
+///   `Err(PodCastError::SizeMismatch)`.
+///
+/// - `from.len() * size_of::<T>()` does not divide evenly into `size_of::<U>()`,
+///   returning a `Err(PodCastError::OutputSliceWouldHaveSlop)`.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{try_cast_slice_unaligned, PodCastError, Unaligned};
+///
+/// type Res<T> = Result<T, PodCastError>;
+///
+/// const OK: Res<&[Unaligned<u32>]> = try_cast_slice_unaligned(&[3u16, 5, 8, 13]);
+/// const ERR_SLOP: Res<&[Unaligned<u32>]> = try_cast_slice_unaligned(&[3u16, 5, 8]);
+/// const ERR_ZST: Res<&[Unaligned<()>]> = try_cast_slice_unaligned(&[3u16, 5, 8]);
+///
+/// assert_eq!(OK.unwrap().len(), 2);
+/// assert_eq!(ERR_SLOP, Err(PodCastError::OutputSliceWouldHaveSlop));
+/// assert_eq!(ERR_ZST, Err(PodCastError::SizeMismatch));
+///
+/// ```
+pub const fn try_cast_slice_unaligned<T, U>(from: &[T]) -> Result<&[Unaligned<U>], PodCastError>
+where
+    T: NoUninit,
+    U: AnyBitPattern,
+{
+    match cast_slice_len::<T, Unaligned<U>>(from.len()) {
+        // SAFETY:
+        // `T: NoUninit` guarantees that `T` contains no uninitialized bytes,
+        // `U: AnyBitPattern` guarantees that U is valid for any bit pattern.
+        // `Unaligned<U>` has an alignment of 1, and the same size as `U`,
+        // `cast_slice_len` checks that the sizes are compatible.
+        Ok(new_len) => unsafe {
+            Ok(core::slice::from_raw_parts(
+                from.as_ptr().cast::<Unaligned<U>>(),
+                new_len,
+            ))
+        },
+        Err(e) => Err(e),
+    }
+}
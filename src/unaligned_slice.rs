@@ -0,0 +1,376 @@
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use bytemuck::{AnyBitPattern, PodCastError};
+
+use crate::{
+    __priv_utils::{out_of_bounds_range_panic, subslice},
+    pod::read_unaligned_unchecked,
+    slice_fns::{cast_slice_len, cast_slice_panic},
+    unaligned::Unaligned,
+};
+
+/// A slice of `T`s stored in a byte slice,
+/// which doesn't require the bytes to be aligned for `T`.
+///
+/// The elements are read by value, with the same layout as
+/// [`pod_read_unaligned`](crate::pod_read_unaligned),
+/// which means that integers are read in native endianness.
+///
+/// This can be iterated over in const with the [`iter`](Self::iter) method,
+/// and with the [`konst`] iteration macros if the `"konst"` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use constmuck::{AnyBitPattern, UnalignedSlice};
+///
+/// #[repr(C)]
+/// #[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+/// struct Record {
+///     id: u16,
+///     len: u16,
+/// }
+///
+/// // the records start after a 1 byte header, so they're not aligned for `u16`
+/// const BUFFER: &[u8] = &[2, 1, 0, 3, 0, 2, 0, 5, 0];
+///
+/// const RECORDS: UnalignedSlice<'_, Record> = UnalignedSlice::new(match BUFFER {
+///     [_header, records @ ..] => records,
+///     [] => &[],
+/// });
+///
+/// const TOTAL_LEN: u16 = {
+///     let mut total = 0;
+///     let mut iter = RECORDS.iter();
+///     while let Some((record, next)) = iter.next() {
+///         total += u16::from_le(record.len);
+///         iter = next;
+///     }
+///     total
+/// };
+///
+/// assert_eq!(RECORDS.len(), 2);
+/// assert_eq!(RECORDS.get(1).map(|r| u16::from_le(r.id)), Some(2));
+/// assert_eq!(RECORDS.get(2), None);
+/// assert_eq!(TOTAL_LEN, 8);
+///
+/// ```
+///
+/// [`konst`]: https://docs.rs/konst/0.3/konst/index.html
+pub struct UnalignedSlice<'a, T> {
+    // invariant: `bytes.len()` is a multiple of `size_of::<T>()`, which is non-zero
+    bytes: &'a [u8],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Copy for UnalignedSlice<'a, T> {}
+
+impl<'a, T> Clone for UnalignedSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: AnyBitPattern> UnalignedSlice<'a, T> {
+    /// Constructs an `UnalignedSlice` of all the `T`s in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics in the cases where [`try_new`](Self::try_new)
+    /// returns [an error](Self::try_new).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::UnalignedSlice;
+    ///
+    /// const SLICE: UnalignedSlice<'_, [u8; 2]> = UnalignedSlice::new(&[3, 5, 8, 13]);
+    ///
+    /// assert_eq!(SLICE.len(), 2);
+    /// assert_eq!(SLICE.get(1), Some([8, 13]));
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        match Self::try_new(bytes) {
+            Ok(x) => x,
+            Err(e) => cast_slice_panic::<u8, Unaligned<T>>(bytes.len(), e),
+        }
+    }
+
+    /// Constructs an `UnalignedSlice` of all the `T`s in `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns errors in these cases:
+    /// - `T` is zero-sized, returning a `Err(PodCastError::SizeMismatch)`.
+    ///
+    /// - `bytes.len()` is not a multiple of `size_of::<T>()`,
+    ///   returning a `Err(PodCastError::OutputSliceWouldHaveSlop)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::{PodCastError, UnalignedSlice};
+    ///
+    /// const OK: Option<usize> = match UnalignedSlice::<u32>::try_new(&[0; 8]) {
+    ///     Ok(slice) => Some(slice.len()),
+    ///     Err(_) => None,
+    /// };
+    /// assert_eq!(OK, Some(2));
+    ///
+    /// assert!(matches!(
+    ///     UnalignedSlice::<u32>::try_new(&[0; 7]),
+    ///     Err(PodCastError::OutputSliceWouldHaveSlop),
+    /// ));
+    /// assert!(matches!(
+    ///     UnalignedSlice::<()>::try_new(&[]),
+    ///     Err(PodCastError::SizeMismatch),
+    /// ));
+    ///
+    /// ```
+    pub const fn try_new(bytes: &'a [u8]) -> Result<Self, PodCastError> {
+        match cast_slice_len::<u8, Unaligned<T>>(bytes.len()) {
+            Ok(_) => Ok(Self {
+                bytes,
+                _marker: PhantomData,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The amount of `T`s in this slice.
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / size_of::<T>()
+    }
+
+    /// Whether this slice contains no `T`s.
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The bytes of the `T`s in this slice.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Reads the `T` at `index`, returning `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::UnalignedSlice;
+    ///
+    /// const SLICE: UnalignedSlice<'_, u16> = UnalignedSlice::new(&[0, 1, 0, 2, 0, 3]);
+    ///
+    /// const ELEMS: [Option<u16>; 3] = [SLICE.get(0), SLICE.get(2), SLICE.get(3)];
+    ///
+    /// assert_eq!(ELEMS, [Some(u16::from_be(1)), Some(u16::from_be(3)), None]);
+    ///
+    /// ```
+    pub const fn get(&self, index: usize) -> Option<T> {
+        if index < self.len() {
+            // SAFETY: the `index` element is in bounds,
+            //         and `T: AnyBitPattern` is valid for any bit pattern.
+            unsafe { Some(self.read_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Divides this slice into two at `mid`,
+    /// the first slice contains the `[0, mid)` elements,
+    /// the second one contains the `[mid, len)` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::UnalignedSlice;
+    ///
+    /// const SLICE: UnalignedSlice<'_, [u8; 2]> = UnalignedSlice::new(&[3, 5, 8, 13, 21, 34]);
+    ///
+    /// const SPLIT: (UnalignedSlice<'_, [u8; 2]>, UnalignedSlice<'_, [u8; 2]>) = SLICE.split_at(1);
+    ///
+    /// assert_eq!(SPLIT.0.as_bytes(), [3, 5]);
+    /// assert_eq!(SPLIT.1.as_bytes(), [8, 13, 21, 34]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn split_at(self, mid: usize) -> (Self, Self) {
+        let len = self.len();
+        (self.subslice(0, mid), self.subslice(mid, len))
+    }
+
+    /// Gets the `[start, end)` range of elements of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or `end > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use constmuck::UnalignedSlice;
+    ///
+    /// const SLICE: UnalignedSlice<'_, u8> = UnalignedSlice::new(&[3, 5, 8, 13, 21]);
+    ///
+    /// const SUB: UnalignedSlice<'_, u8> = SLICE.subslice(1, 4);
+    ///
+    /// assert_eq!(SUB.as_bytes(), [5, 8, 13]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn subslice(self, start: usize, end: usize) -> Self {
+        let size = size_of::<T>();
+
+        if start > end || end > self.len() {
+            out_of_bounds_range_panic(start, end, self.len())
+        }
+
+        Self {
+            bytes: subslice(self.bytes, start * size, end * size),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets a const iterator over the `T`s in this slice.
+    pub const fn iter(self) -> UnalignedSliceIter<'a, T> {
+        UnalignedSliceIter { slice: self }
+    }
+
+    /// Gets a const iterator over the `T`s in this slice,
+    /// used by the [`konst`] iteration macros.
+    ///
+    /// [`konst`]: https://docs.rs/konst/0.3/konst/index.html
+    pub const fn const_into_iter(self) -> UnalignedSliceIter<'a, T> {
+        self.iter()
+    }
+
+    // # Safety
+    //
+    // `index` must be less than `self.len()`
+    const unsafe fn read_unchecked(&self, index: usize) -> T {
+        let size = size_of::<T>();
+        read_unaligned_unchecked(subslice(self.bytes, index * size, (index + 1) * size))
+    }
+}
+
+impl<'a, T: AnyBitPattern + Debug> Debug for UnalignedSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for i in 0..self.len() {
+            // SAFETY: `i` is less than `self.len()`
+            let _ = list.entry(&unsafe { self.read_unchecked(i) });
+        }
+        list.finish()
+    }
+}
+
+macro_rules! declare_iter {
+    ($Iter:ident, $Rev:ident, $forward:literal, $next:ident, $next_back:ident) => {
+        impl<'a, T> Copy for $Iter<'a, T> {}
+
+        impl<'a, T> Clone for $Iter<'a, T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<'a, T: AnyBitPattern> $Iter<'a, T> {
+            #[doc = concat!("Removes and returns the ", $forward, " element of the iterator.")]
+            pub const fn next(self) -> Option<(T, Self)> {
+                self.$next()
+            }
+
+            /// Removes and returns the element at the opposite end from [`next`](Self::next).
+            pub const fn next_back(self) -> Option<(T, Self)> {
+                self.$next_back()
+            }
+
+            /// Reverses the iterator.
+            pub const fn rev(self) -> $Rev<'a, T> {
+                $Rev { slice: self.slice }
+            }
+
+            /// Creates a copy of this iterator.
+            pub const fn copy(&self) -> Self {
+                *self
+            }
+
+            /// The elements that haven't been iterated over.
+            pub const fn remaining(&self) -> UnalignedSlice<'a, T> {
+                self.slice
+            }
+
+            const fn pop_front(self) -> Option<(T, Self)> {
+                let len = self.slice.len();
+                if len == 0 {
+                    return None;
+                }
+
+                // SAFETY: the slice is not empty
+                let elem = unsafe { self.slice.read_unchecked(0) };
+                let slice = self.slice.subslice(1, len);
+                Some((elem, Self { slice }))
+            }
+
+            const fn pop_back(self) -> Option<(T, Self)> {
+                let len = self.slice.len();
+                if len == 0 {
+                    return None;
+                }
+
+                // SAFETY: the slice is not empty
+                let elem = unsafe { self.slice.read_unchecked(len - 1) };
+                let slice = self.slice.subslice(0, len - 1);
+                Some((elem, Self { slice }))
+            }
+        }
+
+        impl<'a, T: AnyBitPattern + Debug> Debug for $Iter<'a, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($Iter)).field(&self.slice).finish()
+            }
+        }
+
+        #[cfg(feature = "konst")]
+        impl<'a, T> konst::iter::ConstIntoIter for $Iter<'a, T> {
+            type Kind = konst::iter::IsIteratorKind;
+            type IntoIter = Self;
+            type Item = T;
+        }
+    };
+}
+
+/// Const iterator over the elements of an [`UnalignedSlice`], from the front.
+///
+/// Returned by [`UnalignedSlice::iter`].
+pub struct UnalignedSliceIter<'a, T> {
+    slice: UnalignedSlice<'a, T>,
+}
+
+declare_iter! {UnalignedSliceIter, UnalignedSliceIterRev, "first", pop_front, pop_back}
+
+/// Const iterator over the elements of an [`UnalignedSlice`], from the back.
+///
+/// Returned by [`UnalignedSliceIter::rev`].
+pub struct UnalignedSliceIterRev<'a, T> {
+    slice: UnalignedSlice<'a, T>,
+}
+
+declare_iter! {UnalignedSliceIterRev, UnalignedSliceIter, "last", pop_back, pop_front}
+
+#[cfg(feature = "konst")]
+impl<'a, T> konst::iter::ConstIntoIter for UnalignedSlice<'a, T> {
+    type Kind = konst::iter::IsIntoIterKind;
+    type IntoIter = UnalignedSliceIter<'a, T>;
+    type Item = T;
+}
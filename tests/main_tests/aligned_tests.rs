@@ -0,0 +1,121 @@
+use super::test_utils::must_panic;
+
+use constmuck::{
+    cast_slice_from_aligned, from_bytes_aligned, try_cast_slice_from_aligned,
+    try_from_bytes_aligned, Align1, Align16, Align2, Align32, Align4, Align64, Align8, Aligned,
+    Alignment,
+    PodCastError::{OutputSliceWouldHaveSlop, SizeMismatch},
+};
+
+use core::mem::{align_of, size_of};
+
+#[test]
+fn alignment_markers_test() {
+    macro_rules! test_marker {
+        ($($ty:ident = $align:literal),*) => {$(
+            assert_eq!(<$ty as Alignment>::ALIGN, $align);
+            assert_eq!(align_of::<$ty>(), $align);
+            assert_eq!(size_of::<$ty>(), 0);
+            assert_eq!(align_of::<Aligned<$ty, u8>>(), $align);
+            assert_eq!(align_of::<Aligned<$ty, u128>>(), $align.max(align_of::<u128>()));
+        )*};
+    }
+
+    test_marker! {
+        Align1 = 1, Align2 = 2, Align4 = 4, Align8 = 8, Align16 = 16, Align32 = 32, Align64 = 64
+    }
+}
+
+#[test]
+fn aligned_type_test() {
+    let x = Aligned::<Align8, [u8; 3]>::new([3, 5, 8]);
+    assert_eq!(x.value, [3, 5, 8]);
+    assert_eq!(size_of::<Aligned<Align8, [u8; 3]>>(), 8);
+    assert_eq!(format!("{:?}", x), "Aligned([3, 5, 8])");
+
+    let y = x;
+    assert_eq!(x, y);
+    assert_eq!(Aligned::<Align4, u16>::default().value, 0);
+    assert_eq!(
+        constmuck::zeroed::<Aligned<Align16, [u32; 2]>>().value,
+        [0, 0]
+    );
+
+    let unsized_: &Aligned<Align8, [u8]> = &x;
+    assert_eq!(unsized_.value, [3, 5, 8]);
+    assert_eq!(unsized_ as *const _ as *const u8 as usize % 8, 0);
+}
+
+#[test]
+fn cast_slice_from_aligned_test() {
+    let bytes: &Aligned<Align8, [u8]> =
+        &Aligned::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    let u64s: &[u64] = cast_slice_from_aligned(bytes);
+    assert_eq!(
+        u64s,
+        [
+            u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]),
+            u64::from_ne_bytes([9, 10, 11, 12, 13, 14, 15, 16]),
+        ]
+    );
+
+    let u16s: &[u16] = cast_slice_from_aligned(bytes);
+    assert_eq!(u16s.len(), 8);
+    assert_eq!(u16s[7], u16::from_ne_bytes([15, 16]));
+
+    let quads: &[[u8; 4]] = cast_slice_from_aligned(bytes);
+    assert_eq!(quads[1], [5, 6, 7, 8]);
+
+    // `T` being more aligned than `A`
+    let words: &Aligned<Align1, [u32]> = &Aligned::new([3, 5]);
+    let halves: &[u16] = cast_slice_from_aligned(words);
+    assert_eq!(halves.len(), 4);
+
+    let empty: &Aligned<Align16, [u8]> = &Aligned::new([]);
+    assert_eq!(cast_slice_from_aligned::<_, _, u128>(empty), [0u128; 0]);
+
+    must_panic(|| cast_slice_from_aligned::<_, u8, [u64; 3]>(bytes)).unwrap();
+    must_panic(|| cast_slice_from_aligned::<_, u8, ()>(bytes)).unwrap();
+
+    assert_eq!(
+        try_cast_slice_from_aligned::<_, u8, u32>(bytes).map(|x| x.len()),
+        Ok(4)
+    );
+    assert_eq!(
+        try_cast_slice_from_aligned::<_, u8, [u32; 3]>(bytes),
+        Err(OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(
+        try_cast_slice_from_aligned::<_, u8, ()>(bytes),
+        Err(SizeMismatch)
+    );
+}
+
+#[test]
+fn from_bytes_aligned_test() {
+    let bytes: &Aligned<Align16, [u8]> = &Aligned::new([0xAB; 16]);
+
+    assert_eq!(
+        from_bytes_aligned::<_, u128>(bytes),
+        &u128::from_ne_bytes([0xAB; 16])
+    );
+    assert_eq!(from_bytes_aligned::<_, [u32; 4]>(bytes), &[0xABAB_ABAB; 4]);
+
+    must_panic(|| from_bytes_aligned::<_, u64>(bytes)).unwrap();
+
+    assert_eq!(
+        try_from_bytes_aligned::<_, [u16; 8]>(bytes),
+        Ok(&[0xABAB; 8])
+    );
+    assert_eq!(try_from_bytes_aligned::<_, u32>(bytes), Err(SizeMismatch));
+
+    let odd: &Aligned<Align2, [u8]> = &Aligned::new([1, 0, 2, 0, 3, 0]);
+    assert_eq!(
+        from_bytes_aligned::<_, [u16; 3]>(odd),
+        &[[1, 0], [2, 0], [3, 0]].map(u16::from_ne_bytes)
+    );
+
+    let unit: &Aligned<Align32, [u8]> = &Aligned::new([]);
+    assert_eq!(from_bytes_aligned::<_, ()>(unit), &());
+}
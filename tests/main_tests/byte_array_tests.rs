@@ -0,0 +1,29 @@
+use super::test_utils::Pack;
+
+use constmuck::{from_byte_array, from_byte_array_ref, to_byte_array, to_byte_array_ref};
+
+#[test]
+fn to_byte_array_test() {
+    assert_eq!(to_byte_array::<u32, 4>(0x01020304u32.to_be()), [1, 2, 3, 4]);
+    assert_eq!(to_byte_array::<[i8; 2], 2>([-1, 1]), [255, 1]);
+    assert_eq!(to_byte_array::<(), 0>(()), []);
+}
+
+#[test]
+fn from_byte_array_test() {
+    assert_eq!(
+        from_byte_array::<u32, 4>([1, 2, 3, 4]),
+        0x01020304u32.to_be()
+    );
+    assert_eq!(from_byte_array::<Pack<u16>, 2>([0; 2]), Pack(0));
+}
+
+#[test]
+fn byte_array_ref_test() {
+    assert_eq!(to_byte_array_ref::<u16, 2>(&0x0102u16.to_be()), &[1, 2]);
+    assert_eq!(
+        from_byte_array_ref::<Pack<u16>, 2>(&[1, 2]),
+        &Pack(0x0102u16.to_be())
+    );
+    assert_eq!(from_byte_array_ref::<[i8; 3], 3>(&[1, 2, 255]), &[1, 2, -1]);
+}
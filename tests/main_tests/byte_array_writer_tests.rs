@@ -0,0 +1,82 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::ByteArrayWriter;
+
+#[test]
+fn write_test() {
+    let writer = ByteArrayWriter::<8>::new();
+    assert_eq!(writer.offset(), 0);
+    assert_eq!(writer.remaining(), 8);
+
+    let writer = writer.write(0x0102u16.to_be());
+    assert_eq!(writer.offset(), 2);
+    assert_eq!(writer.remaining(), 6);
+
+    let writer = writer.write(Pack(0x03040506u32.to_be())).write(());
+    assert_eq!(writer.offset(), 6);
+
+    must_panic(|| writer.write(0u32)).unwrap();
+
+    let writer = writer.write([7u8, 8]);
+    assert_eq!(writer.remaining(), 0);
+    assert_eq!(writer.finish(), [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    must_panic(|| writer.write(0u8)).unwrap();
+}
+
+#[test]
+fn write_bytes_test() {
+    let writer = ByteArrayWriter::<5>::new().write_bytes(b"ab");
+    assert_eq!(writer.finish(), *b"ab\0\0\0");
+
+    must_panic(|| writer.write_bytes(b"cdef")).unwrap();
+
+    let writer = writer.write_bytes(b"").write_bytes(b"cde");
+    assert_eq!(writer.finish(), *b"abcde");
+}
+
+#[test]
+fn pad_to_test() {
+    let writer = ByteArrayWriter::<6>::new();
+
+    must_panic(|| writer.pad_to(0)).unwrap();
+
+    assert_eq!(writer.pad_to(4).offset(), 0);
+
+    let writer = writer.write(1u8).pad_to(2);
+    assert_eq!(writer.offset(), 2);
+    assert_eq!(writer.pad_to(2).offset(), 2);
+    assert_eq!(writer.pad_to(3).offset(), 3);
+    assert_eq!(writer.pad_to(6).offset(), 6);
+
+    must_panic(|| writer.pad_to(7)).unwrap();
+
+    assert_eq!(writer.write(2u8).pad_to(4).finish(), [1, 0, 2, 0, 0, 0]);
+}
+
+#[test]
+fn default_test() {
+    assert_eq!(ByteArrayWriter::<4>::default(), ByteArrayWriter::<4>::new());
+}
+
+#[test]
+fn concat_bytes_of_test() {
+    const EMPTY: [u8; 0] = constmuck::concat_bytes_of!();
+    assert_eq!(EMPTY, []);
+
+    const ONE: [u8; 4] = constmuck::concat_bytes_of!(0x01020304u32.to_be());
+    assert_eq!(ONE, [1, 2, 3, 4]);
+
+    const HEADER: Pack<u32> = Pack(0x0A0B0C0Du32.to_be());
+    const TABLE: [u16; 2] = [0x0102u16.to_be(), 0x0304u16.to_be()];
+    const TRAILER: u8 = 0xFF;
+
+    const ALL: [u8; 9] = constmuck::concat_bytes_of!(HEADER, TABLE, (), TRAILER,);
+    assert_eq!(ALL, [0xA, 0xB, 0xC, 0xD, 1, 2, 3, 4, 0xFF]);
+
+    const NESTED: [u8; 13] = constmuck::concat_bytes_of!(ALL, HEADER);
+    assert_eq!(
+        NESTED,
+        [0xA, 0xB, 0xC, 0xD, 1, 2, 3, 4, 0xFF, 0xA, 0xB, 0xC, 0xD]
+    );
+}
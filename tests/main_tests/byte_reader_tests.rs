@@ -0,0 +1,81 @@
+use super::test_utils::Pack;
+
+use constmuck::{ByteReader, ByteReaderError};
+
+#[track_caller]
+fn assert_err(res: ByteReaderError, offset: usize, requested: usize, available: usize) {
+    assert_eq!(res.offset(), offset);
+    assert_eq!(res.requested(), requested);
+    assert_eq!(res.available(), available);
+}
+
+#[test]
+fn read_test() {
+    let reader = ByteReader::new(&[3, 5, 8, 13, 21, 34, 55]);
+    assert_eq!(reader.offset(), 0);
+
+    let (a, reader) = reader.read::<u8>().unwrap();
+    assert_eq!(a, 3);
+    assert_eq!(reader.offset(), 1);
+
+    let (b, reader) = reader.read::<Pack<u32>>().unwrap();
+    assert_eq!(b, Pack(u32::from_ne_bytes([5, 8, 13, 21])));
+    assert_eq!(reader.offset(), 5);
+    assert_eq!(reader.remaining(), [34, 55]);
+
+    assert_err(reader.read::<[u8; 3]>().unwrap_err(), 5, 3, 2);
+
+    let (c, reader) = reader.read::<[u8; 2]>().unwrap();
+    assert_eq!(c, [34, 55]);
+    assert_eq!(reader.remaining(), [0u8; 0]);
+
+    let ((), reader) = reader.read::<()>().unwrap();
+    assert_err(reader.read::<u8>().unwrap_err(), 7, 1, 0);
+}
+
+#[test]
+fn read_array_test() {
+    let reader = ByteReader::new(&[3, 5, 8, 13, 21]);
+
+    let (arr, reader) = reader.read_array::<Pack<u16>, 2>().unwrap();
+    assert_eq!(
+        arr,
+        [
+            Pack(u16::from_ne_bytes([3, 5])),
+            Pack(u16::from_ne_bytes([8, 13]))
+        ]
+    );
+
+    assert_err(reader.read_array::<u8, 2>().unwrap_err(), 4, 2, 1);
+    assert_eq!(reader.read_array::<u8, 1>().unwrap().0, [21]);
+}
+
+#[test]
+fn peek_test() {
+    let reader = ByteReader::new(&[3, 5, 8]);
+
+    assert_eq!(reader.peek::<[u8; 2]>().unwrap(), [3, 5]);
+    assert_eq!(reader.peek::<[u8; 3]>().unwrap(), [3, 5, 8]);
+    assert_err(reader.peek::<[u8; 4]>().unwrap_err(), 0, 4, 3);
+    assert_eq!(reader.offset(), 0);
+}
+
+#[test]
+fn skip_and_read_bytes_test() {
+    let reader = ByteReader::new(&[3, 5, 8, 13, 21]);
+
+    let reader = reader.skip(1).unwrap();
+    assert_eq!(reader.remaining(), [5, 8, 13, 21]);
+
+    assert_err(reader.skip(5).unwrap_err(), 1, 5, 4);
+
+    let (bytes, reader) = reader.read_bytes(3).unwrap();
+    assert_eq!(bytes, [5, 8, 13]);
+    assert_eq!(reader.offset(), 4);
+
+    assert_err(reader.read_bytes(2).unwrap_err(), 4, 2, 1);
+
+    let reader = reader.skip(1).unwrap();
+    assert_eq!(reader.remaining(), [0u8; 0]);
+    assert_eq!(reader.skip(0).unwrap().offset(), 5);
+}
@@ -0,0 +1,236 @@
+use super::test_utils::Pack;
+
+use constmuck::{
+    checked::{
+        try_cast, try_cast_ref, try_cast_slice, try_pod_read_unaligned, CheckedBitPattern,
+        CheckedCastError::{self, InvalidBitPattern},
+        Validator,
+    },
+    Contiguous,
+    PodCastError::{
+        OutputSliceWouldHaveSlop, SizeMismatch, TargetAlignmentGreaterAndInputNotAligned,
+    },
+};
+
+use core::num::{NonZeroI32, NonZeroI8, NonZeroU16, NonZeroU64, NonZeroU8};
+
+const fn pod_err(e: constmuck::PodCastError) -> CheckedCastError {
+    CheckedCastError::PodCastError(e)
+}
+
+#[repr(i8)]
+#[derive(Debug, PartialEq, Contiguous, Copy, Clone)]
+enum Signed {
+    A = -2,
+    B = -1,
+    C = 0,
+    D = 1,
+}
+
+impl CheckedBitPattern for Signed {
+    const VALIDATOR: Validator<Self> = Validator::contiguous();
+}
+
+#[repr(u64)]
+#[derive(Debug, PartialEq, Contiguous, Copy, Clone)]
+enum Unsigned {
+    A = 0xFFFF_FFFF_FFFF_FFFE,
+    B = 0xFFFF_FFFF_FFFF_FFFF,
+}
+
+impl CheckedBitPattern for Unsigned {
+    const VALIDATOR: Validator<Self> = Validator::contiguous();
+}
+
+#[test]
+fn try_cast_test() {
+    assert_eq!(try_cast::<u8, bool>(0), Ok(false));
+    assert_eq!(try_cast::<u8, bool>(1), Ok(true));
+    assert_eq!(try_cast::<u8, bool>(2), Err(InvalidBitPattern));
+    assert_eq!(try_cast::<u16, bool>(0), Err(pod_err(SizeMismatch)));
+
+    assert_eq!(try_cast::<u32, char>(0), Ok('\0'));
+    assert_eq!(try_cast::<u32, char>(0xD7FF), Ok('\u{D7FF}'));
+    assert_eq!(try_cast::<u32, char>(0xD800), Err(InvalidBitPattern));
+    assert_eq!(try_cast::<u32, char>(0xDFFF), Err(InvalidBitPattern));
+    assert_eq!(try_cast::<u32, char>(0xE000), Ok('\u{E000}'));
+    assert_eq!(try_cast::<u32, char>(0x10FFFF), Ok('\u{10FFFF}'));
+    assert_eq!(try_cast::<u32, char>(0x110000), Err(InvalidBitPattern));
+    assert_eq!(try_cast::<i32, char>(-1), Err(InvalidBitPattern));
+
+    assert_eq!(try_cast::<u8, NonZeroU8>(0), Err(InvalidBitPattern));
+    assert_eq!(
+        try_cast::<u8, NonZeroU8>(255),
+        Ok(NonZeroU8::new(255).unwrap())
+    );
+    assert_eq!(try_cast::<i8, NonZeroI8>(0), Err(InvalidBitPattern));
+    assert_eq!(
+        try_cast::<i8, NonZeroI8>(-128),
+        Ok(NonZeroI8::new(-128).unwrap())
+    );
+    assert_eq!(
+        try_cast::<u32, NonZeroI32>(1 << 31),
+        Ok(NonZeroI32::new(i32::MIN).unwrap())
+    );
+    assert_eq!(
+        try_cast::<[u32; 2], NonZeroU64>([0, 1]),
+        Ok(NonZeroU64::new(u64::from_ne_bytes(constmuck::cast([0u32, 1]))).unwrap())
+    );
+    assert_eq!(
+        try_cast::<[u32; 2], NonZeroU64>([0, 0]),
+        Err(InvalidBitPattern)
+    );
+
+    assert_eq!(try_cast::<i8, Signed>(-3), Err(InvalidBitPattern));
+    assert_eq!(try_cast::<i8, Signed>(-2), Ok(Signed::A));
+    assert_eq!(try_cast::<u8, Signed>(255), Ok(Signed::B));
+    assert_eq!(try_cast::<i8, Signed>(1), Ok(Signed::D));
+    assert_eq!(try_cast::<i8, Signed>(2), Err(InvalidBitPattern));
+
+    assert_eq!(try_cast::<u64, Unsigned>(0), Err(InvalidBitPattern));
+    assert_eq!(
+        try_cast::<u64, Unsigned>(u64::MAX - 2),
+        Err(InvalidBitPattern)
+    );
+    assert_eq!(try_cast::<u64, Unsigned>(u64::MAX - 1), Ok(Unsigned::A));
+    assert_eq!(try_cast::<i64, Unsigned>(-1), Ok(Unsigned::B));
+
+    assert_eq!(try_cast::<i32, u32>(-1), Ok(u32::MAX));
+    assert_eq!(try_cast::<u32, f32>(0x3F80_0000), Ok(1.0));
+}
+
+#[test]
+fn try_cast_ref_test() {
+    assert_eq!(try_cast_ref::<u8, bool>(&1), Ok(&true));
+    assert_eq!(try_cast_ref::<u8, bool>(&3), Err(InvalidBitPattern));
+    assert_eq!(try_cast_ref::<u32, char>(&0x61), Ok(&'a'));
+    assert_eq!(
+        try_cast_ref::<[u8; 4], char>(&[0x61, 0, 0, 0]),
+        Err(pod_err(TargetAlignmentGreaterAndInputNotAligned))
+    );
+    assert_eq!(try_cast_ref::<u64, char>(&0x61), Err(pod_err(SizeMismatch)));
+    assert_eq!(
+        try_cast_ref::<u16, NonZeroU16>(&7),
+        Ok(&NonZeroU16::new(7).unwrap())
+    );
+    assert_eq!(try_cast_ref::<i8, Signed>(&0), Ok(&Signed::C));
+}
+
+#[test]
+fn try_cast_slice_test() {
+    assert_eq!(try_cast_slice::<u8, bool>(&[]), Ok(&[][..]));
+    assert_eq!(
+        try_cast_slice::<u8, bool>(&[1, 0, 1]),
+        Ok(&[true, false, true][..])
+    );
+    assert_eq!(
+        try_cast_slice::<u8, bool>(&[1, 0, 1, 5]),
+        Err(InvalidBitPattern)
+    );
+    assert_eq!(
+        try_cast_slice::<[u8; 2], Signed>(&[[254, 255], [0, 1]]),
+        Ok(&[Signed::A, Signed::B, Signed::C, Signed::D][..])
+    );
+    assert_eq!(
+        try_cast_slice::<u64, char>(&[u64::from_ne_bytes(constmuck::cast([0x61u32, 0x62]))]),
+        Ok(&['a', 'b'][..])
+    );
+    assert_eq!(
+        try_cast_slice::<u64, char>(&[u64::from_ne_bytes(constmuck::cast([0x61u32, 0xD800]))]),
+        Err(InvalidBitPattern)
+    );
+    assert_eq!(
+        try_cast_slice::<u32, NonZeroU64>(&[1, 1, 1]),
+        Err(pod_err(TargetAlignmentGreaterAndInputNotAligned))
+    );
+    assert_eq!(
+        try_cast_slice::<[u16; 3], NonZeroU16>(&[[1, 2, 3]]),
+        Ok(&[1, 2, 3].map(|x| NonZeroU16::new(x).unwrap())[..])
+    );
+    assert_eq!(
+        try_cast_slice::<Pack<[u8; 3]>, bool>(&[Pack([0, 1, 0])]),
+        Ok(&[false, true, false][..])
+    );
+    assert_eq!(
+        try_cast_slice::<u32, char>(&[0x61, 0x110000]),
+        Err(InvalidBitPattern)
+    );
+    assert_eq!(
+        try_cast_slice::<(), bool>(&[()]),
+        Err(pod_err(SizeMismatch))
+    );
+    assert_eq!(
+        try_cast_slice::<u16, Unsigned>(&[0; 3]),
+        Err(pod_err(TargetAlignmentGreaterAndInputNotAligned))
+    );
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, constmuck::AnyBitPattern)]
+struct Pair([u8; 2]);
+
+impl CheckedBitPattern for Pair {
+    const VALIDATOR: Validator<Self> = Validator::any_bit_pattern();
+}
+
+#[test]
+fn try_cast_slice_slop_test() {
+    assert_eq!(
+        try_cast_slice::<u8, Pair>(&[3, 5, 8, 13]),
+        Ok(&[Pair([3, 5]), Pair([8, 13])][..])
+    );
+    assert_eq!(
+        try_cast_slice::<u8, Pair>(&[3, 5, 8]),
+        Err(pod_err(OutputSliceWouldHaveSlop))
+    );
+    assert_eq!(
+        try_cast_slice::<[u8; 3], Pair>(&[[3, 5, 8], [13, 21, 34]]),
+        Ok(&[Pair([3, 5]), Pair([8, 13]), Pair([21, 34])][..])
+    );
+}
+
+#[test]
+fn try_pod_read_unaligned_test() {
+    assert_eq!(try_pod_read_unaligned::<bool>(&[1]), Ok(true));
+    assert_eq!(
+        try_pod_read_unaligned::<bool>(&[1, 0]),
+        Err(pod_err(SizeMismatch))
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<bool>(&[]),
+        Err(pod_err(SizeMismatch))
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<char>(&0x1F600u32.to_ne_bytes()),
+        Ok('😀')
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<char>(&0xDABCu32.to_ne_bytes()),
+        Err(InvalidBitPattern)
+    );
+
+    // reading from an unaligned address
+    let mut bytes = [0u8; 9];
+    bytes[1..].copy_from_slice(&(u64::MAX - 1).to_ne_bytes());
+    assert_eq!(
+        try_pod_read_unaligned::<Unsigned>(&bytes[1..]),
+        Ok(Unsigned::A)
+    );
+    bytes[1..].copy_from_slice(&(u64::MAX - 2).to_ne_bytes());
+    assert_eq!(
+        try_pod_read_unaligned::<Unsigned>(&bytes[1..]),
+        Err(InvalidBitPattern)
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<Unsigned>(&u64::MAX.to_ne_bytes()),
+        Ok(Unsigned::B)
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<NonZeroU64>(&bytes[1..]).map(|x| x.get()),
+        Ok(u64::MAX - 2)
+    );
+    assert_eq!(
+        try_pod_read_unaligned::<NonZeroU64>(&[0; 8]),
+        Err(InvalidBitPattern)
+    );
+}
@@ -0,0 +1,57 @@
+use super::test_utils::Pack;
+
+use constmuck::{is_zeroed, pod_cmp, pod_eq, slice_pod_eq};
+
+use core::cmp::Ordering::{Equal, Greater, Less};
+
+#[test]
+fn pod_eq_test() {
+    assert!(pod_eq(&(), &()));
+    assert!(pod_eq(&[3u8, 5], &[3, 5]));
+    assert!(!pod_eq(&[3u8, 5], &[3, 6]));
+    assert!(!pod_eq(&[3u8, 5], &[4, 5]));
+    assert!(pod_eq(&Pack(0x1234u32), &Pack(0x1234)));
+    assert!(!pod_eq(&Pack(0x1234u32), &Pack(0x1235)));
+
+    assert!(pod_eq(&f32::NAN, &f32::NAN));
+    assert!(!pod_eq(&0.0f64, &-0.0f64));
+}
+
+#[test]
+fn pod_cmp_test() {
+    assert_eq!(pod_cmp(&(), &()), Equal);
+    assert_eq!(pod_cmp(&[3u8, 5], &[3, 5]), Equal);
+    assert_eq!(pod_cmp(&[3u8, 5], &[3, 6]), Less);
+    assert_eq!(pod_cmp(&[3u8, 5], &[3, 4]), Greater);
+    assert_eq!(pod_cmp(&[3u8, 5], &[4, 0]), Less);
+    assert_eq!(pod_cmp(&[4u8, 0], &[3, 5]), Greater);
+
+    for (l, r) in [(0u32, 1u32), (0xFF, 0x100), (1, u32::MAX), (7, 7)] {
+        assert_eq!(pod_cmp(&l.to_be(), &r.to_be()), l.cmp(&r));
+        assert_eq!(pod_cmp(&l.to_ne_bytes(), &r.to_ne_bytes()), pod_cmp(&l, &r));
+    }
+}
+
+#[test]
+fn slice_pod_eq_test() {
+    assert!(slice_pod_eq::<u32>(&[], &[]));
+    assert!(slice_pod_eq(&[3u32, 5], &[3, 5]));
+    assert!(!slice_pod_eq(&[3u32, 5], &[3]));
+    assert!(!slice_pod_eq(&[3u32], &[3, 5]));
+    assert!(!slice_pod_eq(&[3u32, 5], &[3, 6]));
+
+    // zero-sized elements are only compared by length
+    assert!(slice_pod_eq(&[(); 3], &[(); 3]));
+    assert!(!slice_pod_eq(&[(); 3], &[(); 2]));
+}
+
+#[test]
+fn is_zeroed_test() {
+    assert!(is_zeroed(&()));
+    assert!(is_zeroed(&0u8));
+    assert!(is_zeroed(&[0u128; 3]));
+    assert!(is_zeroed(&0.0f32));
+    assert!(!is_zeroed(&-0.0f32));
+    assert!(!is_zeroed(&[0u16, 0, 1]));
+    assert!(!is_zeroed(&Pack(1u64 << 63)));
+}
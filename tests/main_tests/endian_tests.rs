@@ -0,0 +1,81 @@
+use super::test_utils::must_panic;
+
+use constmuck::endian::{
+    read_be, read_le, I128Be, I128Le, I16Be, I16Le, I32Be, I32Le, I64Be, I64Le, U128Be, U128Le,
+    U16Be, U16Le, U32Be, U32Le, U64Be, U64Le,
+};
+
+use core::mem::{align_of, size_of};
+
+macro_rules! test_endian_type {
+    ($Int:ident, $Le:ident, $Be:ident) => {{
+        assert_eq!(size_of::<$Le>(), size_of::<$Int>());
+        assert_eq!(size_of::<$Be>(), size_of::<$Int>());
+        assert_eq!(align_of::<$Le>(), 1);
+        assert_eq!(align_of::<$Be>(), 1);
+
+        for value in [0, 1, 0x7F, $Int::MIN, $Int::MAX, $Int::MAX / 3] {
+            let le = $Le::new(value);
+            let be = $Be::new(value);
+
+            assert_eq!(le.get(), value);
+            assert_eq!(be.get(), value);
+            assert_eq!(le.to_bytes(), value.to_le_bytes());
+            assert_eq!(be.to_bytes(), value.to_be_bytes());
+            assert_eq!($Le::from_bytes(value.to_le_bytes()), le);
+            assert_eq!($Be::from_bytes(value.to_be_bytes()), be);
+
+            assert_eq!($Le::from(value), le);
+            assert_eq!($Int::from(be), value);
+
+            assert_eq!(format!("{:?}", le), format!("{:?}", value));
+            assert_eq!(format!("{:x?}", be), format!("{:x?}", value));
+
+            assert_eq!(constmuck::bytes_of(&le), value.to_le_bytes());
+            assert_eq!(constmuck::bytes_of(&be), value.to_be_bytes());
+
+            assert_eq!(read_le::<$Int>(&value.to_le_bytes()), value);
+            assert_eq!(read_be::<$Int>(&value.to_be_bytes()), value);
+        }
+
+        assert_eq!($Le::default().get(), 0);
+        assert_eq!($Be::default().get(), 0);
+        assert_eq!(constmuck::zeroed::<$Le>(), $Le::new(0));
+    }};
+}
+
+#[test]
+fn endian_types_test() {
+    test_endian_type! {u16, U16Le, U16Be}
+    test_endian_type! {u32, U32Le, U32Be}
+    test_endian_type! {u64, U64Le, U64Be}
+    test_endian_type! {u128, U128Le, U128Be}
+    test_endian_type! {i16, I16Le, I16Be}
+    test_endian_type! {i32, I32Le, I32Be}
+    test_endian_type! {i64, I64Le, I64Be}
+    test_endian_type! {i128, I128Le, I128Be}
+}
+
+#[test]
+fn endian_byte_order_test() {
+    assert_eq!(U32Le::new(0x01020304).to_bytes(), [4, 3, 2, 1]);
+    assert_eq!(U32Be::new(0x01020304).to_bytes(), [1, 2, 3, 4]);
+    assert_eq!(I16Le::new(-2).to_bytes(), [0xFE, 0xFF]);
+    assert_eq!(I16Be::new(-2).to_bytes(), [0xFF, 0xFE]);
+}
+
+#[test]
+fn read_test() {
+    assert_eq!(read_le::<u8>(&[3]), 3);
+    assert_eq!(read_be::<i8>(&[0xFF]), -1);
+    assert_eq!(read_le::<u16>(&[1, 2]), 0x0201);
+    assert_eq!(read_be::<u16>(&[1, 2]), 0x0102);
+    assert_eq!(read_le::<i32>(&[0xFE, 0xFF, 0xFF, 0xFF]), -2);
+    assert_eq!(read_be::<u64>(&[0, 0, 0, 0, 0, 0, 1, 0]), 256);
+    assert_eq!(read_le::<usize>(&1usize.to_le_bytes()), 1,);
+    assert_eq!(read_be::<isize>(&(-5isize).to_be_bytes()), -5,);
+
+    must_panic(|| read_le::<u16>(&[1])).unwrap();
+    must_panic(|| read_be::<u16>(&[1, 2, 3])).unwrap();
+    must_panic(|| read_be::<u32>(&[])).unwrap();
+}
@@ -0,0 +1,81 @@
+use super::test_utils::Pack;
+
+use constmuck::hash::{crc32, crc32_slice, fnv1a_64, fnv1a_64_slice, xxh32, xxh32_slice};
+
+const fn byte_table<const N: usize>() -> [u8; N] {
+    let mut arr = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = i as u8;
+        i += 1;
+    }
+    arr
+}
+
+const BYTES_768: [u8; 768] = byte_table();
+
+const WORD_BYTES: [u8; 8] = [0x78, 0x56, 0x34, 0x12, 0xEF, 0xCD, 0xAB, 0x89];
+
+// has the same bytes as `WORD_BYTES` on every target
+const WORDS: [u32; 2] = [0x1234_5678u32.to_le(), 0x89AB_CDEFu32.to_le()];
+
+#[test]
+fn crc32_test() {
+    assert_eq!(crc32(&()), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(&BYTES_768), 0xB0C0_DF2A);
+    assert_eq!(crc32(&WORD_BYTES), 0x11A5_0243);
+    assert_eq!(crc32(&WORDS), 0x11A5_0243);
+    assert_eq!(crc32(&Pack(WORDS)), 0x11A5_0243);
+
+    assert_eq!(crc32_slice::<u64>(&[]), 0);
+    assert_eq!(crc32_slice(&[(); 4]), 0);
+    assert_eq!(crc32_slice(&BYTES_768[..]), 0xB0C0_DF2A);
+    assert_eq!(crc32_slice(&WORDS[..]), 0x11A5_0243);
+}
+
+#[test]
+fn fnv1a_64_test() {
+    assert_eq!(fnv1a_64(&()), 0xCBF2_9CE4_8422_2325);
+    assert_eq!(fnv1a_64(b"a"), 0xAF63_DC4C_8601_EC8C);
+    assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_F739_67E8);
+    assert_eq!(fnv1a_64(&BYTES_768), 0x4F33_C513_C2E9_5C25);
+    assert_eq!(fnv1a_64(&WORDS), 0x5C5D_6CA7_ACCC_719D);
+
+    assert_eq!(fnv1a_64_slice::<u64>(&[]), 0xCBF2_9CE4_8422_2325);
+    assert_eq!(fnv1a_64_slice(&BYTES_768[..]), 0x4F33_C513_C2E9_5C25);
+    assert_eq!(fnv1a_64_slice(&WORDS[..]), 0x5C5D_6CA7_ACCC_719D);
+}
+
+#[test]
+fn xxh32_test() {
+    assert_eq!(xxh32(&(), 0), 0x02CC_5D05);
+    assert_eq!(xxh32(b"abc", 0), 0x32D1_53FF);
+    assert_eq!(xxh32(b"abc", 1), 0xAA3D_A8FF);
+    assert_eq!(xxh32(&WORDS, 0), 0xA103_2A5C);
+
+    // lengths around the 16-byte stripes, and the 4-byte tail
+    for (len, hash) in [
+        (7, 0xA743_36DC),
+        (15, 0x57C0_1ECE),
+        (16, 0xB728_37F4),
+        (17, 0x7C77_ADC2),
+    ] {
+        assert_eq!(xxh32_slice(&BYTES_768[..len], 0), hash, "{len}");
+    }
+
+    assert_eq!(xxh32_slice(&BYTES_768[..100], 0x9E37_79B1), 0xFA99_6F24);
+    assert_eq!(xxh32_slice::<u64>(&[], 0), 0x02CC_5D05);
+    assert_eq!(xxh32_slice(&WORDS[..], 0), 0xA103_2A5C);
+}
+
+#[test]
+fn const_hash_test() {
+    const HASHES: (u32, u64, u32) = (
+        crc32(b"123456789"),
+        fnv1a_64(b"foobar"),
+        xxh32_slice(b"Nobody inspects the spammish repetition", 0),
+    );
+
+    assert_eq!(HASHES, (0xCBF4_3926, 0x8594_4171_F739_67E8, 0xE229_3B2F));
+}
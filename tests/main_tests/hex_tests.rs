@@ -0,0 +1,101 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{pod_from_hex, to_hex_array, to_hex_array_upper, Pod, Zeroable};
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Pod, Zeroable)]
+struct Uuid {
+    time_low: [u8; 4],
+    time_mid: [u8; 2],
+    time_hi_and_version: [u8; 2],
+    rest: [u8; 8],
+}
+
+#[test]
+fn to_hex_array_test() {
+    assert_eq!(to_hex_array::<(), 0>(&()), []);
+    assert_eq!(&to_hex_array::<_, 2>(&0u8), b"00");
+    assert_eq!(&to_hex_array::<_, 2>(&0xFFu8), b"ff");
+    assert_eq!(&to_hex_array::<_, 6>(&[0x01u8, 0xA0, 0x5C]), b"01a05c");
+    assert_eq!(
+        &to_hex_array::<_, 8>(&Pack(0x0123_ABCDu32.to_be())),
+        b"0123abcd"
+    );
+    assert_eq!(&to_hex_array::<_, 8>(&0x0123_ABCDu32.to_le()), b"cdab2301");
+
+    const ALL: [u8; 512] = to_hex_array(&{
+        let mut arr = [0u8; 256];
+        let mut i = 0;
+        while i < 256 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    });
+    for (i, pair) in ALL.chunks(2).enumerate() {
+        assert_eq!(pair, format!("{:02x}", i).as_bytes());
+    }
+}
+
+#[test]
+fn to_hex_array_upper_test() {
+    assert_eq!(to_hex_array_upper::<(), 0>(&()), []);
+    assert_eq!(
+        &to_hex_array_upper::<_, 6>(&[0x01u8, 0xA0, 0x5C]),
+        b"01A05C"
+    );
+    assert_eq!(
+        &to_hex_array_upper::<_, 8>(&0xDEAD_BEEFu32.to_be()),
+        b"DEADBEEF"
+    );
+}
+
+#[test]
+fn pod_from_hex_test() {
+    assert_eq!(pod_from_hex!("" as ()), ());
+    assert_eq!(pod_from_hex!("00" as u8), 0);
+    assert_eq!(pod_from_hex!("fF" as u8), 0xFF);
+    assert_eq!(pod_from_hex!("0a1B" as [u8; 2]), [0x0A, 0x1B]);
+    assert_eq!(pod_from_hex!("deadbeef" as u32), u32::from_be(0xDEAD_BEEF));
+    assert_eq!(
+        pod_from_hex!("0123456789ABCDEFabcdef" as Pack<[u8; 11]>),
+        Pack([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xAB, 0xCD, 0xEF]),
+    );
+}
+
+// `pod_from_hex` parses the string in a constant, so the runtime panics are tested
+// by calling the function that it uses.
+#[test]
+fn parse_hex_test() {
+    use constmuck::__::parse_hex;
+
+    assert_eq!(parse_hex::<0>(""), []);
+    assert_eq!(parse_hex::<2>("0aF9"), [0x0A, 0xF9]);
+
+    // invalid digits
+    for hex in ["0g", "g0", "0x", " 0", "0-", "\u{e9}"] {
+        must_panic(|| parse_hex::<1>(hex)).unwrap();
+    }
+    must_panic(|| parse_hex::<3>("12345z")).unwrap();
+
+    // wrong lengths
+    must_panic(|| parse_hex::<0>("00")).unwrap();
+    must_panic(|| parse_hex::<1>("")).unwrap();
+    must_panic(|| parse_hex::<1>("0")).unwrap();
+    must_panic(|| parse_hex::<1>("000")).unwrap();
+    must_panic(|| parse_hex::<2>("00")).unwrap();
+    must_panic(|| parse_hex::<2>("000000")).unwrap();
+}
+
+#[test]
+fn hex_round_trip_test() {
+    const ID: Uuid = pod_from_hex!("123e4567e89b12d3a456426614174000" as Uuid);
+    const HEX: [u8; 32] = to_hex_array(&ID);
+
+    assert_eq!(ID.time_mid, [0xE8, 0x9B]);
+    assert_eq!(&HEX, b"123e4567e89b12d3a456426614174000");
+    assert_eq!(
+        &to_hex_array_upper::<_, 32>(&ID),
+        b"123E4567E89B12D3A456426614174000"
+    );
+}
@@ -0,0 +1,78 @@
+use constmuck::{include_pod, include_pod_slice};
+
+use core::mem::align_of;
+
+const FIBS: [u16; 8] = [1, 1, 2, 3, 5, 8, 13, 21];
+
+fn is_aligned<T>(ptr: *const T) -> bool {
+    ptr as usize % align_of::<T>() == 0
+}
+
+#[test]
+fn include_pod_test() {
+    const BIG: &[u16; 8] = include_pod!("../data/fib_u16be.bin" as [u16; 8], endian = big);
+    const NATIVE: &[u16; 8] = include_pod!("../data/fib_u16be.bin" as [u16; 8]);
+    const NATIVE_EXPLICIT: &[u16; 8] =
+        include_pod!("../data/fib_u16be.bin" as [u16; 8], endian = native,);
+    const LITTLE: &[u16; 8] = include_pod!("../data/fib_u16be.bin" as [u16; 8], endian = little);
+
+    assert_eq!(BIG, &FIBS);
+    assert_eq!(NATIVE, &FIBS.map(u16::to_be));
+    assert_eq!(NATIVE_EXPLICIT, NATIVE);
+    assert_eq!(LITTLE, &FIBS.map(u16::swap_bytes));
+
+    const WIDE: &u128 = include_pod!("../data/fib_u16be.bin" as u128, endian = big);
+    assert!(is_aligned(WIDE));
+    assert_eq!(
+        *WIDE,
+        u128::from_be_bytes(constmuck::cast(FIBS.map(u16::to_be)))
+    );
+
+    const NESTED: &[[u32; 2]; 2] =
+        include_pod!("../data/fib_u16be.bin" as [[u32; 2]; 2], endian = big);
+    assert!(is_aligned(NESTED));
+    assert_eq!(
+        NESTED,
+        &[[0x0001_0001, 0x0002_0003], [0x0005_0008, 0x000d_0015]]
+    );
+}
+
+#[test]
+fn include_pod_slice_test() {
+    const BIG: &[u16] = include_pod_slice!("../data/fib_u16be.bin" as u16, endian = big);
+    const NATIVE: &[u16] = include_pod_slice!("../data/fib_u16be.bin" as u16);
+    const LITTLE: &[u16] = include_pod_slice!("../data/fib_u16be.bin" as u16, endian = little);
+
+    assert_eq!(BIG, FIBS);
+    assert_eq!(NATIVE, FIBS.map(u16::to_be));
+    assert_eq!(LITTLE, FIBS.map(u16::swap_bytes));
+
+    const WORDS: &[u64] = include_pod_slice!("../data/fib_u16be.bin" as u64, endian = big);
+    assert!(is_aligned(WORDS.as_ptr()));
+    assert_eq!(WORDS, [0x0001_0001_0002_0003, 0x0005_0008_000d_0015]);
+
+    const PAIRS: &[[u32; 2]] =
+        include_pod_slice!("../data/fib_u16be.bin" as [u32; 2], endian = big);
+    assert!(is_aligned(PAIRS.as_ptr()));
+    assert_eq!(
+        PAIRS,
+        [[0x0001_0001, 0x0002_0003], [0x0005_0008, 0x000d_0015]]
+    );
+
+    const LITTLE_WORDS: &[u32] =
+        include_pod_slice!("../data/fib_u16be.bin" as u32, endian = little);
+    assert!(is_aligned(LITTLE_WORDS.as_ptr()));
+    assert_eq!(
+        LITTLE_WORDS,
+        [0x0001_0001, 0x0002_0003, 0x0005_0008, 0x000d_0015].map(u32::swap_bytes)
+    );
+
+    const FLOATS: &[f32] = include_pod_slice!("../data/fib_u16be.bin" as f32, endian = big);
+    assert_eq!(
+        FLOATS.iter().map(|f| f.to_bits()).collect::<Vec<u32>>(),
+        [0x0001_0001, 0x0002_0003, 0x0005_0008, 0x000d_0015]
+    );
+
+    const BYTES: &[u8] = include_pod_slice!("../data/fib_u16be.bin" as u8, endian = little);
+    assert_eq!(BYTES, constmuck::bytes_of(&FIBS.map(u16::to_be)));
+}
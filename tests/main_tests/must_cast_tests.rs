@@ -0,0 +1,39 @@
+use super::test_utils::Pack;
+
+use constmuck::{must_cast, must_cast_ref, must_cast_slice};
+
+#[test]
+fn must_cast_test() {
+    assert_eq!(must_cast::<u32, i32>(u32::MAX), -1i32);
+    assert_eq!(must_cast::<u32, Pack<u32>>(1), Pack(1));
+    assert_eq!(must_cast::<[u16; 2], u32>([0; 2]), 0);
+    assert_eq!(must_cast::<(), [u8; 0]>(()), []);
+}
+
+#[test]
+fn must_cast_ref_test() {
+    assert_eq!(must_cast_ref::<u32, Pack<i32>>(&u32::MAX), &Pack(-1i32));
+    assert_eq!(must_cast_ref::<u32, i32>(&u32::MAX), &-1i32);
+    assert_eq!(
+        must_cast_ref::<u16, [u8; 2]>(&0x0102u16.to_le()),
+        &[0x02, 0x01]
+    );
+}
+
+#[test]
+fn must_cast_slice_test() {
+    assert_eq!(must_cast_slice::<(), ()>(&[(); 3]), &[(); 3][..]);
+
+    assert_eq!(
+        must_cast_slice::<u32, u16>(&[0x01020304u32.to_le()]),
+        &[0x03_04, 0x01_02_u16][..]
+    );
+    assert_eq!(
+        must_cast_slice::<u32, Pack<i32>>(&[u32::MAX, 1]),
+        &[Pack(-1i32), Pack(1)][..]
+    );
+    assert_eq!(
+        must_cast_slice::<[u8; 4], [u8; 2]>(&[[3, 5, 8, 13]]),
+        &[[3, 5], [8, 13]][..]
+    );
+}
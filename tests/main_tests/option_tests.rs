@@ -0,0 +1,83 @@
+use super::test_utils::must_panic;
+
+use constmuck::{
+    cast_from_option, cast_option, try_cast_from_option, try_cast_option, zeroed_option,
+    PodCastError::SizeMismatch,
+};
+
+use core::{
+    num::{NonZeroI64, NonZeroU128, NonZeroU32, NonZeroU8, NonZeroUsize},
+    ptr::NonNull,
+};
+
+#[test]
+fn cast_option_test() {
+    assert_eq!(cast_option::<u8, NonZeroU8>(0), None);
+    assert_eq!(cast_option::<u8, NonZeroU8>(1), NonZeroU8::new(1));
+    assert_eq!(cast_option::<i64, NonZeroI64>(-1), NonZeroI64::new(-1));
+    assert_eq!(cast_option::<[u8; 4], NonZeroU32>([0, 0, 0, 0]), None);
+    assert_eq!(
+        cast_option::<[u64; 2], NonZeroU128>([1, 0]),
+        NonZeroU128::new(u128::from_ne_bytes(constmuck::cast([1u64, 0])))
+    );
+
+    must_panic(|| cast_option::<u16, NonZeroU8>(0)).unwrap();
+    must_panic(|| cast_option::<u8, NonZeroU32>(0)).unwrap();
+
+    assert_eq!(try_cast_option::<u32, NonZeroU32>(0), Ok(None));
+    assert_eq!(
+        try_cast_option::<u32, NonZeroU32>(9),
+        Ok(NonZeroU32::new(9))
+    );
+    assert_eq!(try_cast_option::<u64, NonZeroU32>(9), Err(SizeMismatch));
+}
+
+#[test]
+fn cast_from_option_test() {
+    assert_eq!(cast_from_option::<NonZeroU8, u8>(None), 0);
+    assert_eq!(cast_from_option::<NonZeroU8, i8>(NonZeroU8::new(255)), -1);
+    assert_eq!(
+        cast_from_option::<NonZeroU32, [u16; 2]>(NonZeroU32::new(u32::MAX)),
+        [u16::MAX; 2]
+    );
+    assert_eq!(
+        cast_from_option::<NonZeroUsize, usize>(NonZeroUsize::new(3)),
+        3
+    );
+
+    must_panic(|| cast_from_option::<NonZeroU8, u16>(None)).unwrap();
+
+    assert_eq!(try_cast_from_option::<NonZeroU32, u32>(None), Ok(0));
+    assert_eq!(
+        try_cast_from_option::<NonZeroU32, i32>(NonZeroU32::new(5)),
+        Ok(5)
+    );
+    assert_eq!(
+        try_cast_from_option::<NonZeroU32, u8>(NonZeroU32::new(5)),
+        Err(SizeMismatch)
+    );
+}
+
+#[test]
+fn round_trip_test() {
+    for n in [0u32, 1, 2, 1000, u32::MAX] {
+        let opt: Option<NonZeroU32> = cast_option(n);
+        assert_eq!(opt, NonZeroU32::new(n));
+        assert_eq!(cast_from_option::<_, u32>(opt), n);
+    }
+}
+
+#[test]
+fn zeroed_option_test() {
+    assert_eq!(zeroed_option::<&u8>(), None);
+    assert_eq!(zeroed_option::<&mut [u8]>(), None);
+    assert_eq!(zeroed_option::<NonZeroU8>(), None);
+    assert_eq!(zeroed_option::<NonNull<str>>(), None);
+    assert!(zeroed_option::<fn() -> u32>().is_none());
+    assert!(zeroed_option::<unsafe extern "C" fn(u8)>().is_none());
+
+    assert_eq!(
+        zeroed_option::<&[u8; 1 << 22]>(),
+        constmuck::zeroed::<Option<&[u8; 1 << 22]>>()
+    );
+}
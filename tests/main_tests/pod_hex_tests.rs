@@ -0,0 +1,63 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{assert_pod_eq, PodHex};
+
+use const_panic::concat_panic;
+
+fn panic_message(err: &super::test_utils::ThreadError) -> &str {
+    err.downcast_ref::<String>().unwrap()
+}
+
+#[test]
+fn pod_hex_debug_test() {
+    assert_eq!(format!("{:?}", PodHex::new(&())), "[]");
+    assert_eq!(
+        format!("{:?}", PodHex::new(&[0u8, 0xA, 0xFF])),
+        "[0, A, FF]"
+    );
+    assert_eq!(
+        format!("{:?}", PodHex::new(&Pack(0x1234_5678u32.to_be()))),
+        "[12, 34, 56, 78]"
+    );
+}
+
+#[test]
+fn pod_hex_accessors_test() {
+    const ARR: [u16; 2] = [3, 5];
+    const HEX: PodHex<'_, [u16; 2]> = PodHex::new(&ARR);
+
+    assert_eq!(HEX.get(), &ARR);
+    assert_eq!(HEX.bytes(), constmuck::bytes_of(&ARR));
+}
+
+#[test]
+fn pod_hex_panic_test() {
+    let err = must_panic(|| concat_panic!("value: ", PodHex::new(&[0x10u8, 0x2, 0xAB]))).unwrap();
+    assert_eq!(panic_message(&err), "value: [10, 2, AB]");
+
+    let err = must_panic(|| concat_panic!({}: PodHex::new(&0x0102u16.to_be()))).unwrap();
+    assert_eq!(panic_message(&err), "[1, 2]");
+}
+
+#[test]
+fn assert_pod_eq_test() {
+    const _: () = assert_pod_eq!((), ());
+    const _: () = assert_pod_eq!([3u32, 5], [3, 5]);
+
+    assert_pod_eq!(f32::NAN, f32::NAN);
+    must_panic(|| assert_pod_eq!(0.0f32, -0.0f32)).unwrap();
+
+    let err = must_panic(|| assert_pod_eq!([1u8, 2, 3], [1, 2, 4])).unwrap();
+    assert_eq!(
+        panic_message(&err),
+        concat!(
+            "\nassertion failed: the bytes of `left` and `right` are not equal",
+            "\nfirst differing byte offset: 2",
+            "\n left: [1, 2, 3]",
+            "\nright: [1, 2, 4]",
+        )
+    );
+
+    let err = must_panic(|| assert_pod_eq!(Pack(0xAB00u16.to_be()), Pack(0xCD00u16.to_be())));
+    assert!(panic_message(&err.unwrap()).contains("offset: 0\n left: [AB, 0]\nright: [CD, 0]"));
+}
@@ -168,3 +168,163 @@ fn try_pod_read_unaligned_test() {
     assert_eq!(tpru::<u32>(&[1; 5]).unwrap_err(), SizeMismatch);
     assert_eq!(tpru::<u32>(&[1; 6]).unwrap_err(), SizeMismatch);
 }
+
+#[cfg(feature = "rust_1_83")]
+#[test]
+fn cast_mut_test() {
+    use constmuck::{cast_mut, try_cast_mut};
+
+    must_panic(|| {
+        cast_mut::<u16, u32>(&mut 0);
+    })
+    .unwrap();
+    must_panic(|| {
+        cast_mut::<u32, u16>(&mut 0);
+    })
+    .unwrap();
+    must_panic(|| {
+        cast_mut::<Pack<u32>, u32>(&mut Pack(0));
+    })
+    .unwrap();
+
+    assert_eq!(try_cast_mut::<u16, Pack<u32>>(&mut 0), Err(SizeMismatch));
+    assert_eq!(
+        try_cast_mut::<Pack<u32>, u32>(&mut Pack(0)),
+        Err(TargetAlignmentGreaterAndInputNotAligned)
+    );
+
+    let mut num = 0u32;
+    *cast_mut::<u32, i32>(&mut num) = -1;
+    assert_eq!(num, u32::MAX);
+
+    *try_cast_mut::<u32, Pack<i32>>(&mut num).unwrap() = Pack(-2);
+    assert_eq!(num, u32::MAX - 1);
+}
+
+#[cfg(feature = "rust_1_83")]
+#[test]
+fn bytes_of_mut_test() {
+    use constmuck::bytes_of_mut;
+
+    let mut num = 0u32;
+    bytes_of_mut(&mut num).copy_from_slice(&123456789u32.to_ne_bytes());
+    assert_eq!(num, 123456789);
+
+    let mut arr = [0i8; 3];
+    bytes_of_mut(&mut arr)[1] = 255;
+    assert_eq!(arr, [0, -1, 0]);
+}
+
+#[cfg(feature = "rust_1_83")]
+#[test]
+fn cast_slice_mut_alt_test() {
+    use constmuck::{cast_slice_mut_alt as csma, try_cast_slice_mut_alt as tcsma};
+
+    must_panic(|| {
+        csma::<Pack<u32>, u32>(&mut [Pack(0)]);
+    })
+    .unwrap();
+    must_panic(|| {
+        csma::<(), u8>(&mut [()]);
+    })
+    .unwrap();
+    must_panic(|| {
+        csma::<[u8; 3], [u8; 2]>(&mut [[0; 3]; 5]);
+    })
+    .unwrap();
+
+    assert_eq!(tcsma::<u8, ()>(&mut [0u8]), Err(SizeMismatch));
+    assert_eq!(
+        tcsma::<Pack<u32>, u32>(&mut [Pack(0)]),
+        Err(TargetAlignmentGreaterAndInputNotAligned)
+    );
+    assert_eq!(
+        tcsma::<u8, Pack<u32>>(&mut [0; 13]),
+        Err(OutputSliceWouldHaveSlop)
+    );
+
+    let mut arr = [0u32; 2];
+    csma::<u32, u16>(&mut arr)[1] = u16::MAX;
+    assert_eq!(arr[0], u32::from_ne_bytes([0, 0, 255, 255]));
+
+    let mut bytes = [0u8; 8];
+    tcsma::<u8, Pack<u32>>(&mut bytes).unwrap()[1] = Pack(u32::MAX);
+    assert_eq!(bytes, [0, 0, 0, 0, 255, 255, 255, 255]);
+}
+
+#[test]
+fn from_bytes_test() {
+    use constmuck::{from_bytes, try_from_bytes};
+
+    assert_eq!(from_bytes::<[u8; 3]>(&[3, 5, 8]), &[3, 5, 8]);
+    assert_eq!(from_bytes::<Pack<u32>>(&[1; 4]), &Pack(0x01_01_01_01u32));
+    assert_eq!(from_bytes::<()>(&[]), &());
+
+    must_panic(|| from_bytes::<[u8; 3]>(&[3, 5])).unwrap();
+    must_panic(|| from_bytes::<[u8; 3]>(&[3, 5, 8, 13])).unwrap();
+    must_panic(|| from_bytes::<u16>(&[3, 5])).unwrap();
+
+    assert_eq!(try_from_bytes::<[i8; 2]>(&[3, 255]), Ok(&[3i8, -1]));
+    assert_eq!(try_from_bytes::<[u8; 2]>(&[3]), Err(SizeMismatch));
+    assert_eq!(try_from_bytes::<[u8; 2]>(&[3, 5, 8]), Err(SizeMismatch));
+    assert_eq!(try_from_bytes::<u32>(&[3; 3]), Err(SizeMismatch));
+    assert_eq!(
+        try_from_bytes::<u32>(&[3; 4]),
+        Err(TargetAlignmentGreaterAndInputNotAligned)
+    );
+}
+
+#[test]
+fn cast_slice_prefix_suffix_alt_test() {
+    use constmuck::{
+        cast_slice_prefix_alt as cspa, cast_slice_suffix_alt as cssa,
+        try_cast_slice_prefix_alt as tcspa, try_cast_slice_suffix_alt as tcssa,
+    };
+
+    must_panic(|| cspa::<Pack<u32>, u32>(&[Pack(0)])).unwrap();
+    must_panic(|| cssa::<Pack<u32>, u32>(&[Pack(0)])).unwrap();
+    must_panic(|| cspa::<(), u8>(&[()])).unwrap();
+    must_panic(|| cssa::<u8, ()>(&[0])).unwrap();
+
+    assert_eq!(tcspa::<u8, ()>(&[0u8]), Err(SizeMismatch));
+    assert_eq!(tcssa::<(), u8>(&[()]), Err(SizeMismatch));
+    assert_eq!(
+        tcspa::<Pack<u32>, u32>(&[Pack(0)]),
+        Err(TargetAlignmentGreaterAndInputNotAligned)
+    );
+    assert_eq!(
+        tcssa::<Pack<u32>, u32>(&[Pack(0)]),
+        Err(TargetAlignmentGreaterAndInputNotAligned)
+    );
+
+    assert_eq!(tcspa::<(), ()>(&[(); 3]), Ok((&[(); 3][..], &[(); 0][..])));
+
+    let arr = [3u8, 5, 8, 13, 21, 34, 55];
+    for len in 0..=arr.len() {
+        let slice = &arr[..len];
+        let split = len - len % 3;
+
+        let (prefix, rem) = cspa::<u8, [u8; 3]>(slice);
+        assert_eq!(prefix, csa::<u8, [u8; 3]>(&slice[..split]));
+        assert_eq!(rem, &slice[split..]);
+
+        let (rem, suffix) = cssa::<u8, [u8; 3]>(slice);
+        assert_eq!(rem, &slice[..len - split]);
+        assert_eq!(suffix, csa::<u8, [u8; 3]>(&slice[len - split..]));
+    }
+
+    let arr = [[3u16, 5], [8, 13], [21, 34], [55, 89], [144, 233]];
+    assert_eq!(
+        cspa::<[u16; 2], [u16; 3]>(&arr),
+        (&[[3, 5, 8], [13, 21, 34]][..], &arr[3..])
+    );
+    assert_eq!(
+        cssa::<[u16; 2], [u16; 3]>(&arr),
+        (&arr[..2], &[[21, 34, 55], [89, 144, 233]][..])
+    );
+
+    assert_eq!(
+        tcspa::<u32, u16>(&[0x01020304u32.to_le()]),
+        Ok((&[0x03_04, 0x01_02_u16][..], &[0u32; 0][..]))
+    );
+}
@@ -0,0 +1,104 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{view_field, AnyBitPattern, PodCastError, PodView};
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+struct Record {
+    tag: u8,
+    pad: [u8; 3],
+    value: u32,
+    pair: Pair,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, AnyBitPattern)]
+struct Pair(u16, u16);
+
+const RECORD_BYTES: [u8; 12] = [7, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+#[test]
+fn new_test() {
+    let bytes = [3u8, 5, 8, 13, 21];
+
+    for len in 0..4 {
+        let view = PodView::<[u8; 4]>::try_new(&bytes[..len]);
+        assert!(matches!(view, Err(PodCastError::SizeMismatch)), "{len}");
+        must_panic(|| PodView::<[u8; 4]>::new(&bytes[..len])).unwrap();
+    }
+
+    for len in 4..=5 {
+        let view = PodView::<[u8; 4]>::new(&bytes[..len]);
+        assert_eq!(view.get(), [3, 5, 8, 13]);
+        assert_eq!(view.as_bytes(), [3, 5, 8, 13]);
+
+        let view = PodView::<[u8; 4]>::try_new(&bytes[..len]).unwrap();
+        assert_eq!(view.get(), [3, 5, 8, 13]);
+    }
+
+    assert_eq!(PodView::<()>::new(&[]).get(), ());
+    assert_eq!(PodView::<()>::new(&[]).as_bytes(), []);
+}
+
+#[test]
+fn unaligned_get_test() {
+    for offset in 0..4 {
+        let mut buffer = [0xFFu8; 16];
+        buffer[offset..offset + 12].copy_from_slice(&RECORD_BYTES);
+
+        let view = PodView::<Record>::new(&buffer[offset..]);
+        assert_eq!(view.get().value, u32::from_ne_bytes([1, 2, 3, 4]));
+        assert_eq!(view.as_bytes(), RECORD_BYTES);
+    }
+
+    let view = PodView::<Pack<u64>>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9][1..]);
+    assert_eq!(
+        view.get(),
+        Pack(u64::from_ne_bytes([2, 3, 4, 5, 6, 7, 8, 9]))
+    );
+}
+
+#[test]
+fn view_field_test() {
+    const VIEW: PodView<'_, Record> = PodView::new(&RECORD_BYTES);
+
+    const TAG: u8 = view_field!(VIEW, Record, tag).get();
+    const PAD: PodView<'_, [u8; 3]> = view_field!(VIEW, Record, pad);
+    const PAIR: PodView<'_, Pair> = view_field!(VIEW, Record, pair,);
+    const SECOND: u16 = view_field!(view_field!(VIEW, Record, pair), Pair, 1).get();
+
+    assert_eq!(TAG, 7);
+    assert_eq!(PAD.as_bytes(), [0, 0, 0]);
+    assert_eq!(view_field!(VIEW, Record, value).as_bytes(), [1, 2, 3, 4]);
+    assert_eq!(PAIR.as_bytes(), [5, 6, 7, 8]);
+    assert_eq!(view_field!(PAIR, Pair, 0).get(), u16::from_ne_bytes([5, 6]));
+    assert_eq!(SECOND, u16::from_ne_bytes([7, 8]));
+
+    // unaligned buffer
+    let bytes = [&[0xFF][..], &RECORD_BYTES].concat();
+    let view = PodView::<Record>::new(&bytes[1..]);
+    assert_eq!(
+        view_field!(view, Record, value).get(),
+        u32::from_ne_bytes([1, 2, 3, 4])
+    );
+    assert_eq!(
+        view_field!(view_field!(view, Record, pair), Pair, 1).as_bytes(),
+        [7, 8]
+    );
+
+    // packed struct
+    let view = PodView::<Pack<u32>>::new(&[1, 2, 3, 4]);
+    assert_eq!(
+        view_field!(view, Pack<u32>, 0).get(),
+        u32::from_ne_bytes([1, 2, 3, 4])
+    );
+}
+
+#[test]
+fn debug_test() {
+    let view = PodView::<Pair>::new(&[1, 0, 2, 0]);
+    assert_eq!(
+        format!("{:?}", view),
+        format!("PodView({:?})", Pair(1u16.to_le(), 2u16.to_le())),
+    );
+}
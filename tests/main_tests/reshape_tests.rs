@@ -0,0 +1,89 @@
+use super::test_utils::must_panic;
+
+use constmuck::{
+    flatten_ref, flatten_slice, reshape, reshape_ref, try_unflatten_slice, unflatten_slice,
+    PodCastError::{OutputSliceWouldHaveSlop, SizeMismatch},
+};
+
+#[test]
+fn reshape_test() {
+    let flat: [&str; 6] = reshape([["a", "b", "c"], ["d", "e", "f"]]);
+    assert_eq!(flat, ["a", "b", "c", "d", "e", "f"]);
+
+    assert_eq!(
+        reshape::<[u8; 6], [[u8; 3]; 2]>([3, 5, 8, 13, 21, 34]),
+        [[3, 5, 8], [13, 21, 34]]
+    );
+    assert_eq!(
+        reshape::<[[u8; 3]; 2], [[u8; 2]; 3]>([[3, 5, 8], [13, 21, 34]]),
+        [[3, 5], [8, 13], [21, 34]]
+    );
+    assert_eq!(
+        reshape::<[[[u8; 2]; 2]; 2], [u8; 8]>([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!(
+        reshape::<[u8; 8], [[[u8; 2]; 2]; 2]>([1, 2, 3, 4, 5, 6, 7, 8]),
+        [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+    );
+    // the innermost arrays are treated as the element type
+    assert_eq!(
+        reshape::<[[[u8; 2]; 2]; 2], [[u8; 2]; 4]>([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]),
+        [[1, 2], [3, 4], [5, 6], [7, 8]]
+    );
+    let empty: [[u8; 3]; 0] = reshape::<[u8; 0], _>([]);
+    assert_eq!(empty, [[0u8; 3]; 0]);
+}
+
+#[test]
+fn reshape_ref_test() {
+    assert_eq!(
+        reshape_ref::<[u16; 4], [[u16; 2]; 2]>(&[3, 5, 8, 13]),
+        &[[3, 5], [8, 13]]
+    );
+    assert_eq!(
+        reshape_ref::<[[String; 1]; 2], [String; 2]>(&[["foo".to_string()], ["bar".to_string()]]),
+        &["foo".to_string(), "bar".to_string()]
+    );
+}
+
+#[test]
+fn flatten_ref_test() {
+    assert_eq!(
+        flatten_ref::<u8, 2, 3, 6>(&[[3, 5], [8, 13], [21, 34]]),
+        &[3, 5, 8, 13, 21, 34]
+    );
+    assert_eq!(flatten_ref::<u8, 0, 3, 0>(&[[], [], []]), &[]);
+}
+
+#[test]
+fn flatten_slice_test() {
+    assert_eq!(flatten_slice::<u8, 2>(&[]), [0u8; 0]);
+    assert_eq!(
+        flatten_slice::<u8, 2>(&[[3, 5], [8, 13], [21, 34]]),
+        [3, 5, 8, 13, 21, 34]
+    );
+    assert_eq!(flatten_slice::<u8, 0>(&[[], []]), [0u8; 0]);
+    assert_eq!(flatten_slice::<(), 3>(&[[(); 3]; 2]), [(); 6]);
+}
+
+#[test]
+fn unflatten_slice_test() {
+    must_panic(|| unflatten_slice::<u8, 0>(&[3])).unwrap();
+    must_panic(|| unflatten_slice::<u8, 2>(&[3, 5, 8])).unwrap();
+    assert_eq!(unflatten_slice::<u8, 2>(&[3, 5, 8, 13]), [[3, 5], [8, 13]]);
+
+    assert_eq!(try_unflatten_slice::<u8, 0>(&[]), Ok(&[][..]));
+    assert_eq!(try_unflatten_slice::<u8, 0>(&[3]), Err(SizeMismatch));
+    assert_eq!(try_unflatten_slice::<u8, 3>(&[]), Ok(&[][..]));
+    for len in [1, 2, 4, 5, 7] {
+        assert_eq!(
+            try_unflatten_slice::<u8, 3>(&[0; 8][..len]),
+            Err(OutputSliceWouldHaveSlop)
+        );
+    }
+    assert_eq!(
+        try_unflatten_slice::<u8, 3>(&[3, 5, 8, 13, 21, 34]),
+        Ok(&[[3, 5, 8], [13, 21, 34]][..])
+    );
+}
@@ -0,0 +1,96 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{
+    cast_extend, cast_truncate, try_cast_extend, try_cast_truncate,
+    Placement::{self, Back, Front},
+    PodCastError::SizeMismatch,
+};
+
+#[test]
+fn cast_extend_test() {
+    assert_eq!(cast_extend::<_, [u8; 4]>([3u8, 5], Front), [3, 5, 0, 0]);
+    assert_eq!(cast_extend::<_, [u8; 4]>([3u8, 5], Back), [0, 0, 3, 5]);
+    assert_eq!(cast_extend::<_, [u8; 2]>([3u8, 5], Front), [3, 5]);
+    assert_eq!(cast_extend::<_, [u8; 2]>([3u8, 5], Back), [3, 5]);
+    assert_eq!(cast_extend::<_, [u8; 0]>((), Back), []);
+    assert_eq!(cast_extend::<_, [u16; 3]>((), Back), [0; 3]);
+
+    assert_eq!(
+        cast_extend::<_, Pack<u64>>(0x0102u16.to_le(), Front),
+        Pack(0x0102u64.to_le())
+    );
+    assert_eq!(
+        cast_extend::<_, u64>(0x0102u16.to_be(), Back),
+        0x0102u64.to_be()
+    );
+
+    // outputs much larger than the input
+    let big = cast_extend::<_, [u8; 300]>([0xFFu8; 3], Back);
+    assert_eq!(big[..297], [0; 297][..]);
+    assert_eq!(big[297..], [0xFF; 3]);
+
+    let big = cast_extend::<_, [u8; 5000]>(Pack([0xFFu8; 3]), Front);
+    assert_eq!(big[..3], [0xFF; 3]);
+    assert!(big[3..].iter().all(|&b| b == 0));
+
+    must_panic(|| cast_extend::<_, u16>(0u32, Front)).unwrap();
+    must_panic(|| cast_extend::<_, ()>(0u8, Back)).unwrap();
+
+    assert_eq!(try_cast_extend::<_, [u8; 3]>(3u8, Back), Ok([0, 0, 3]));
+    assert_eq!(try_cast_extend::<_, u16>(0u32, Front), Err(SizeMismatch));
+    assert_eq!(try_cast_extend::<_, ()>(0u8, Back), Err(SizeMismatch));
+}
+
+#[test]
+#[cfg_attr(not(feature = "rust_1_75"), should_panic)]
+fn cast_extend_into_large_type() {
+    // spawning a thread to ensure that the stack has enough space for the arrays
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(|| {
+            for placement in [Front, Back] {
+                let big = cast_extend::<_, [u8; 1_048_577]>(0xFFu8, placement);
+                assert_eq!(big.iter().filter(|&&b| b == 0xFF).count(), 1);
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn cast_truncate_test() {
+    let arr = [3u8, 5, 8, 13];
+
+    assert_eq!(cast_truncate::<_, [u8; 2]>(arr, Front), [3, 5]);
+    assert_eq!(cast_truncate::<_, [u8; 2]>(arr, Back), [8, 13]);
+    assert_eq!(cast_truncate::<_, [u8; 4]>(arr, Front), arr);
+    assert_eq!(cast_truncate::<_, [u8; 4]>(arr, Back), arr);
+    assert_eq!(cast_truncate::<_, ()>(arr, Back), ());
+
+    assert_eq!(
+        cast_truncate::<_, Pack<u16>>(0x0102_0304u32.to_le(), Front),
+        Pack(0x0304u16.to_le())
+    );
+    assert_eq!(
+        cast_truncate::<_, u16>(0x0102_0304u32.to_be(), Back),
+        0x0304u16.to_be()
+    );
+
+    must_panic(|| cast_truncate::<_, u64>(0u32, Front)).unwrap();
+    must_panic(|| cast_truncate::<_, u8>((), Back)).unwrap();
+
+    assert_eq!(try_cast_truncate::<_, u8>(arr, Back), Ok(13));
+    assert_eq!(try_cast_truncate::<_, u64>(0u32, Front), Err(SizeMismatch));
+    assert_eq!(try_cast_truncate::<_, u8>((), Back), Err(SizeMismatch));
+}
+
+#[test]
+fn extend_truncate_roundtrip_test() {
+    for placement in [Front, Back] {
+        let wide: [u32; 4] = cast_extend([7u16, 9, 11], placement);
+        assert_eq!(cast_truncate::<_, [u16; 3]>(wide, placement), [7, 9, 11]);
+    }
+
+    assert_eq!(format!("{:?}", Placement::Front), "Front");
+}
@@ -0,0 +1,44 @@
+use super::test_utils::must_panic;
+
+use constmuck::{as_array, as_array_chunks, as_rarray_chunks};
+
+#[test]
+fn as_array_chunks_test() {
+    must_panic(|| as_array_chunks::<u8, 0>(&[3, 5])).unwrap();
+    must_panic(|| as_rarray_chunks::<u8, 0>(&[3, 5])).unwrap();
+
+    let arr = [3u16, 5, 8, 13, 21, 34, 55];
+    for len in 0..=arr.len() {
+        let slice = &arr[..len];
+        let split = len - len % 3;
+
+        let (chunks, rem) = as_array_chunks::<u16, 3>(slice);
+        assert_eq!(chunks.len(), len / 3);
+        assert_eq!(chunks.concat(), slice[..split]);
+        assert_eq!(rem, &slice[split..]);
+
+        let (rem, chunks) = as_rarray_chunks::<u16, 3>(slice);
+        assert_eq!(rem, &slice[..len - split]);
+        assert_eq!(chunks.len(), len / 3);
+        assert_eq!(chunks.concat(), slice[len - split..]);
+    }
+
+    assert_eq!(
+        as_array_chunks::<(), 2>(&[(); 5]),
+        (&[[(); 2]; 2][..], &[()][..])
+    );
+    assert_eq!(
+        as_rarray_chunks::<&str, 1>(&["foo", "bar"]),
+        (&[][..], &[["foo"], ["bar"]][..])
+    );
+}
+
+#[test]
+fn as_array_test() {
+    assert_eq!(as_array::<u8, 0>(&[]), Some(&[]));
+    assert_eq!(as_array::<u8, 0>(&[3]), None);
+    assert_eq!(as_array::<u8, 2>(&[3]), None);
+    assert_eq!(as_array::<u8, 2>(&[3, 5]), Some(&[3, 5]));
+    assert_eq!(as_array::<u8, 2>(&[3, 5, 8]), None);
+    assert_eq!(as_array::<&str, 1>(&["foo"]), Some(&["foo"]));
+}
@@ -0,0 +1,99 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{join_pod, split_pod, split_pod_at, try_split_pod_at, PodCastError::SizeMismatch};
+
+#[test]
+fn split_pod_test() {
+    let bytes: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+
+    assert_eq!(
+        split_pod::<_, u64, [u8; 8]>(bytes),
+        (
+            u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]),
+            [9, 10, 11, 12, 13, 14, 15, 16]
+        )
+    );
+    assert_eq!(
+        split_pod::<_, [u8; 1], Pack<u128>>([0u8; 17]),
+        ([0], Pack(0))
+    );
+    assert_eq!(
+        split_pod::<_, u8, [u16; 2]>(Pack([3u8, 5, 8, 13, 21])),
+        (
+            3,
+            [u16::from_ne_bytes([5, 8]), u16::from_ne_bytes([13, 21])]
+        )
+    );
+    assert_eq!(split_pod::<_, (), u32>(7u32), ((), 7));
+    assert_eq!(split_pod::<_, u32, ()>(7u32), (7, ()));
+    assert_eq!(split_pod::<_, (), ()>(()), ((), ()));
+}
+
+#[test]
+fn join_pod_test() {
+    assert_eq!(join_pod::<_, _, [u16; 3]>(3u16, [5u16, 8]), [3, 5, 8]);
+    assert_eq!(
+        join_pod::<_, _, u64>(0x0102_0304u32.to_be(), 0x0506_0708u32.to_be()),
+        0x0102_0304_0506_0708u64.to_be()
+    );
+    assert_eq!(
+        join_pod::<_, _, [u8; 5]>(3u8, Pack(u32::from_ne_bytes([5, 8, 13, 21]))),
+        [3, 5, 8, 13, 21]
+    );
+    assert_eq!(join_pod::<_, _, u8>((), 5u8), 5);
+    assert_eq!(join_pod::<_, _, ()>((), ()), ());
+
+    let (a, b): (u32, [u8; 12]) = split_pod([0x55AAu16; 8]);
+    assert_eq!(join_pod::<_, _, [u16; 8]>(a, b), [0x55AA; 8]);
+}
+
+#[test]
+fn split_pod_at_test() {
+    let arr: [u8; 6] = [3, 5, 8, 13, 21, 34];
+
+    assert_eq!(split_pod_at::<_, u8>(&arr, 0), (3, &arr[1..]));
+    assert_eq!(
+        split_pod_at::<_, u16>(&arr, 1),
+        (u16::from_ne_bytes([5, 8]), &arr[3..])
+    );
+    assert_eq!(split_pod_at::<_, [u8; 2]>(&arr, 4), ([21, 34], &[][..]));
+    assert_eq!(split_pod_at::<_, ()>(&arr, 6), ((), &[][..]));
+    assert_eq!(split_pod_at::<_, [u8; 6]>(&arr, 0), (arr, &[][..]));
+
+    for (offset, size) in [(0, 7), (1, 6), (5, 2), (6, 1), (7, 0), (usize::MAX, 1)] {
+        match size {
+            0 => {
+                must_panic(|| split_pod_at::<_, ()>(&arr, offset)).unwrap();
+                assert_eq!(try_split_pod_at::<_, ()>(&arr, offset), Err(SizeMismatch));
+            }
+            1 => {
+                must_panic(|| split_pod_at::<_, u8>(&arr, offset)).unwrap();
+                assert_eq!(try_split_pod_at::<_, u8>(&arr, offset), Err(SizeMismatch));
+            }
+            2 => {
+                must_panic(|| split_pod_at::<_, u16>(&arr, offset)).unwrap();
+                assert_eq!(try_split_pod_at::<_, u16>(&arr, offset), Err(SizeMismatch));
+            }
+            6 => {
+                must_panic(|| split_pod_at::<_, [u8; 6]>(&arr, offset)).unwrap();
+                assert_eq!(
+                    try_split_pod_at::<_, [u8; 6]>(&arr, offset),
+                    Err(SizeMismatch)
+                );
+            }
+            7 => {
+                must_panic(|| split_pod_at::<_, [u8; 7]>(&arr, offset)).unwrap();
+                assert_eq!(
+                    try_split_pod_at::<_, [u8; 7]>(&arr, offset),
+                    Err(SizeMismatch)
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        try_split_pod_at::<_, u32>(&[3u16, 5, 8], 2),
+        Ok((u32::from_ne_bytes(constmuck::cast([5u16, 8])), &[][..]))
+    );
+}
@@ -0,0 +1,74 @@
+use super::test_utils::Pack;
+
+use constmuck::{static_bytes_of, static_cast_ref};
+
+struct Blob {
+    name: &'static str,
+    bytes: &'static [u8],
+}
+
+const WORDS: [u16; 2] = [0x0102u16.to_be(), 0x0304u16.to_be()];
+
+#[test]
+fn static_bytes_of_test() {
+    const EMPTY: &[u8] = static_bytes_of!(());
+    assert_eq!(EMPTY, []);
+
+    const BYTE: &[u8] = static_bytes_of!(7u8);
+    assert_eq!(BYTE, [7]);
+
+    const PACKED: &[u8] = static_bytes_of!(Pack(0x0A0B_0C0Du32.to_be()));
+    assert_eq!(PACKED, [0xA, 0xB, 0xC, 0xD]);
+
+    static BLOBS: [Blob; 2] = [
+        Blob {
+            name: "words",
+            bytes: static_bytes_of!(WORDS),
+        },
+        Blob {
+            name: "reversed",
+            bytes: static_bytes_of!([WORDS[1], WORDS[0]],),
+        },
+    ];
+
+    assert_eq!(BLOBS[0].name, "words");
+    assert_eq!(BLOBS[0].bytes, [1, 2, 3, 4]);
+    assert_eq!(BLOBS[1].name, "reversed");
+    assert_eq!(BLOBS[1].bytes, [3, 4, 1, 2]);
+
+    // a runtime borrow of the bytes outlives the function that produced them
+    fn get() -> &'static [u8] {
+        static_bytes_of!(WORDS)
+    }
+    assert_eq!(get(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn static_cast_ref_test() {
+    const UNIT: &() = static_cast_ref!(() as ());
+    assert_eq!(UNIT, &());
+
+    const BYTES: &[u8; 4] = static_cast_ref!(WORDS as [u8; 4]);
+    assert_eq!(BYTES, &[1, 2, 3, 4]);
+
+    // the output can be more aligned than the input
+    const WIDE: &u32 = static_cast_ref!(*b"\x01\x02\x03\x04" as u32);
+    assert_eq!(*WIDE, u32::from_ne_bytes([1, 2, 3, 4]));
+
+    // `as` casts in the value are passed through
+    const CASTED: &[u8; 2] = static_cast_ref!(0x0102u32 as u16 as [u8; 2],);
+    assert_eq!(CASTED, &0x0102u16.to_ne_bytes());
+
+    struct Entry {
+        words: &'static [u16; 2],
+        packed: &'static Pack<u32>,
+    }
+
+    static ENTRY: Entry = Entry {
+        words: static_cast_ref!(*b"\x05\x00\x08\x00" as [u16; 2]),
+        packed: static_cast_ref!(WORDS as Pack<u32>),
+    };
+
+    assert_eq!(ENTRY.words, &[5u16.to_le(), 8u16.to_le()]);
+    assert_eq!(ENTRY.packed, &Pack(u32::from_be(0x0102_0304)));
+}
@@ -0,0 +1,151 @@
+use super::test_utils::{must_panic, Pack};
+
+use constmuck::{PodCastError, UnalignedSlice, UnalignedSliceIter};
+
+const BYTES: [u8; 9] = [0xFF, 1, 2, 3, 4, 5, 6, 7, 8];
+
+const fn words(bytes: &[u8]) -> UnalignedSlice<'_, u16> {
+    UnalignedSlice::new(bytes)
+}
+
+#[test]
+fn new_test() {
+    for offset in 0..2 {
+        let bytes = &BYTES[offset..offset + 8];
+        let slice = UnalignedSlice::<u32>::new(bytes);
+        assert_eq!(slice.len(), 2);
+        assert!(!slice.is_empty());
+        assert_eq!(slice.as_bytes(), bytes);
+
+        let slice = UnalignedSlice::<u32>::try_new(bytes).unwrap();
+        assert_eq!(slice.len(), 2);
+    }
+
+    let empty = UnalignedSlice::<u64>::new(&[]);
+    assert_eq!(empty.len(), 0);
+    assert!(empty.is_empty());
+
+    for len in [1, 3, 5, 7] {
+        let res = UnalignedSlice::<u16>::try_new(&BYTES[..len]);
+        assert!(
+            matches!(res, Err(PodCastError::OutputSliceWouldHaveSlop)),
+            "{len}"
+        );
+        must_panic(|| UnalignedSlice::<u16>::new(&BYTES[..len])).unwrap();
+    }
+
+    let res = UnalignedSlice::<()>::try_new(&[]);
+    assert!(matches!(res, Err(PodCastError::SizeMismatch)));
+    must_panic(|| UnalignedSlice::<()>::new(&[])).unwrap();
+}
+
+#[test]
+fn get_test() {
+    let slice = words(&BYTES[1..]);
+
+    assert_eq!(slice.get(0), Some(u16::from_ne_bytes([1, 2])));
+    assert_eq!(slice.get(3), Some(u16::from_ne_bytes([7, 8])));
+    assert_eq!(slice.get(4), None);
+    assert_eq!(slice.get(usize::MAX), None);
+
+    let slice = UnalignedSlice::<Pack<u32>>::new(&BYTES[..8]);
+    assert_eq!(slice.get(1), Some(Pack(u32::from_ne_bytes([4, 5, 6, 7]))));
+}
+
+#[test]
+fn split_at_test() {
+    let slice = words(&BYTES[1..]);
+
+    for mid in 0..=4 {
+        let (left, right) = slice.split_at(mid);
+        assert_eq!(left.as_bytes(), &BYTES[1..1 + mid * 2]);
+        assert_eq!(right.as_bytes(), &BYTES[1 + mid * 2..]);
+    }
+
+    must_panic(|| slice.split_at(5)).unwrap();
+}
+
+#[test]
+fn subslice_test() {
+    let slice = words(&BYTES[1..]);
+
+    assert_eq!(slice.subslice(0, 0).len(), 0);
+    assert_eq!(slice.subslice(4, 4).len(), 0);
+    assert_eq!(slice.subslice(1, 3).as_bytes(), [3, 4, 5, 6]);
+    assert_eq!(slice.subslice(0, 4).as_bytes(), &BYTES[1..]);
+
+    must_panic(|| slice.subslice(3, 2)).unwrap();
+    must_panic(|| slice.subslice(0, 5)).unwrap();
+    must_panic(|| slice.subslice(5, 5)).unwrap();
+}
+
+const fn collect<const N: usize>(mut iter: UnalignedSliceIter<'_, u16>) -> [u16; N] {
+    let mut out = [0u16; N];
+    let mut i = 0;
+    while let Some((elem, next)) = iter.next() {
+        out[i] = elem;
+        iter = next;
+        i += 1;
+    }
+    out
+}
+
+#[test]
+fn iter_test() {
+    const SLICE: UnalignedSlice<'_, u16> = words(&[1, 0, 2, 0, 3, 0, 4, 0]);
+    const FORWARD: [u16; 4] = collect(SLICE.iter());
+
+    assert_eq!(
+        FORWARD,
+        [1u16.to_le(), 2u16.to_le(), 3u16.to_le(), 4u16.to_le()]
+    );
+
+    let iter = SLICE.iter();
+    let (first, iter) = iter.next().unwrap();
+    let (last, iter) = iter.next_back().unwrap();
+    assert_eq!(first, 1u16.to_le());
+    assert_eq!(last, 4u16.to_le());
+    assert_eq!(iter.remaining().as_bytes(), [2, 0, 3, 0]);
+    assert_eq!(iter.copy().remaining().len(), 2);
+
+    let rev = iter.rev();
+    let (elem, rev) = rev.next().unwrap();
+    assert_eq!(elem, 3u16.to_le());
+    let (elem, rev) = rev.next_back().unwrap();
+    assert_eq!(elem, 2u16.to_le());
+    assert!(rev.next().is_none());
+    assert!(rev.next_back().is_none());
+    assert!(rev.rev().next().is_none());
+}
+
+#[test]
+fn debug_test() {
+    let slice = UnalignedSlice::<[u8; 2]>::new(&[3, 5, 8, 13]);
+    assert_eq!(format!("{:?}", slice), "[[3, 5], [8, 13]]");
+    assert_eq!(
+        format!("{:?}", slice.iter().rev()),
+        "UnalignedSliceIterRev([[3, 5], [8, 13]])"
+    );
+}
+
+#[cfg(feature = "konst")]
+#[test]
+fn konst_iter_test() {
+    const SLICE: UnalignedSlice<'_, [u8; 2]> = UnalignedSlice::new(&[3, 5, 8, 13, 21, 34]);
+
+    const SUM: u32 = konst::iter::eval!(SLICE, fold(0, |acc, [l, r]| acc + l as u32 * r as u32));
+    assert_eq!(SUM, 3 * 5 + 8 * 13 + 21 * 34);
+
+    const LAST: Option<[u8; 2]> =
+        konst::iter::eval!(SLICE.iter(), rev(), find(|[l, _]| *l % 2 == 1));
+    assert_eq!(LAST, Some([21, 34]));
+
+    const FIRSTS: [u8; 3] = {
+        let mut out = [0u8; 3];
+        konst::iter::for_each! {(i, [l, _]) in SLICE,enumerate() =>
+            out[i] = l;
+        }
+        out
+    };
+    assert_eq!(FIRSTS, [3, 8, 21]);
+}
@@ -0,0 +1,96 @@
+use super::test_utils::must_panic;
+
+use constmuck::{
+    cast_ref_unaligned, cast_slice_unaligned, try_cast_ref_unaligned, try_cast_slice_unaligned,
+    PodCastError::{OutputSliceWouldHaveSlop, SizeMismatch},
+    Unaligned,
+};
+
+use core::mem::{align_of, size_of};
+
+#[test]
+fn unaligned_type_test() {
+    assert_eq!(align_of::<Unaligned<u64>>(), 1);
+    assert_eq!(size_of::<Unaligned<u64>>(), 8);
+    assert_eq!(size_of::<Unaligned<(u8, u32)>>(), size_of::<(u8, u32)>());
+
+    let x = Unaligned::new(0x1234_5678_u32);
+    assert_eq!(x.get(), 0x1234_5678);
+    assert_eq!(x, Unaligned::from(0x1234_5678));
+    assert!(x < Unaligned::new(0x1234_5679));
+    assert_eq!(format!("{:x?}", x), "Unaligned(12345678)");
+    assert_eq!(Unaligned::<u16>::default().get(), 0);
+    assert_eq!(constmuck::zeroed::<Unaligned<u128>>().get(), 0);
+
+    assert_eq!(
+        constmuck::bytes_of(&Unaligned::new(0xAABBu16)),
+        0xAABBu16.to_ne_bytes()
+    );
+}
+
+#[test]
+fn cast_ref_unaligned_test() {
+    let bytes = [3u8, 5, 8, 13, 21, 34, 55, 89, 144];
+
+    // every offset of the array, to ensure that misaligned references work
+    for start in 0..=1 {
+        let arr: &[u8; 8] = constmuck::as_array(&bytes[start..start + 8]).unwrap();
+        let num: &Unaligned<u64> = cast_ref_unaligned(arr);
+        let mut expected = [0u8; 8];
+        expected.copy_from_slice(&bytes[start..start + 8]);
+        assert_eq!(num.get(), u64::from_ne_bytes(expected));
+    }
+
+    assert_eq!(
+        cast_ref_unaligned::<[u16; 2], u32>(&[1, 2]).get(),
+        constmuck::cast::<[u16; 2], u32>([1, 2])
+    );
+
+    must_panic(|| cast_ref_unaligned::<u8, u16>(&0)).unwrap();
+
+    assert_eq!(
+        try_cast_ref_unaligned::<[u8; 2], u16>(&[0xFF, 0xFF]).map(|x| x.get()),
+        Ok(u16::MAX)
+    );
+    assert_eq!(
+        try_cast_ref_unaligned::<[u8; 2], u32>(&[0xFF, 0xFF]),
+        Err(SizeMismatch)
+    );
+}
+
+#[test]
+fn cast_slice_unaligned_test() {
+    let bytes = [0u8, 1, 0, 2, 0, 3, 0];
+
+    let shorts: &[Unaligned<u16>] = cast_slice_unaligned(&bytes[1..]);
+    assert_eq!(
+        shorts.iter().map(|x| x.get()).collect::<Vec<u16>>(),
+        [[1, 0], [2, 0], [3, 0]].map(u16::from_ne_bytes)
+    );
+
+    let quads: &[Unaligned<[u16; 2]>] = cast_slice_unaligned(&[1u16, 2, 3, 4]);
+    assert_eq!(quads, [Unaligned::new([1, 2]), Unaligned::new([3, 4])]);
+
+    let units: &[Unaligned<u8>] = cast_slice_unaligned(&[] as &[u64]);
+    assert!(units.is_empty());
+
+    must_panic(|| cast_slice_unaligned::<u8, u16>(&[1, 2, 3])).unwrap();
+    must_panic(|| cast_slice_unaligned::<u8, ()>(&[1, 2, 3])).unwrap();
+
+    assert_eq!(
+        try_cast_slice_unaligned::<u8, u32>(&bytes[1..]),
+        Err(OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(
+        try_cast_slice_unaligned::<(), u32>(&[()]),
+        Err(SizeMismatch)
+    );
+    assert_eq!(
+        try_cast_slice_unaligned::<u16, u64>(&[0; 8]).map(|x| x.len()),
+        Ok(2)
+    );
+    assert_eq!(
+        try_cast_slice_unaligned::<u64, u8>(&[0; 2]).map(|x| x.len()),
+        Ok(16)
+    );
+}
@@ -1,10 +1,38 @@
 mod main_tests {
+    // `ShouldHavePanickedAt::span` is only read by its `Debug` impl
+    #[allow(dead_code, clippy::non_canonical_clone_impl)]
     #[macro_use]
     mod test_utils;
 
+    mod aligned_tests;
+    mod byte_array_tests;
+    mod byte_array_writer_tests;
+    mod byte_reader_tests;
+    mod checked_tests;
+    mod compare_tests;
+    // some imports are only used with the "debug_checks" feature
+    #[allow(unused_imports)]
     mod contiguous_tests;
+    mod endian_tests;
+    mod hash_tests;
+    mod hex_tests;
+    mod include_pod_tests;
+    mod must_cast_tests;
+    mod option_tests;
+    mod pod_hex_tests;
     mod pod_tests;
+    mod pod_view_tests;
+    mod reshape_tests;
+    mod resize_tests;
+    mod slice_fns_tests;
+    mod split_tests;
+    mod static_bytes_tests;
     mod ui_tests;
+    mod unaligned_slice_tests;
+    mod unaligned_tests;
+    // some imports and items are only used with Miri and the "debug_checks" feature
+    #[allow(unused_imports, dead_code, clippy::bool_assert_comparison)]
     mod wrapper_tests;
+    #[allow(clippy::zero_ptr)]
     mod zeroable;
 }